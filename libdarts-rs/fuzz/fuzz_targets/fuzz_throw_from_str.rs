@@ -0,0 +1,22 @@
+#![no_main]
+
+use libdarts_rs::throw::{InvalidThrowError, Throw};
+use libfuzzer_sys::fuzz_target;
+
+// The parser must never panic and every parsed throw has to survive a round trip
+// through its notation.
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    match Throw::from_str(text) {
+        Ok(throw) => assert_eq!(Throw::from_str(&throw.notation()), Ok(throw)),
+        Err(
+            InvalidThrowError::BullseyeTriple
+            | InvalidThrowError::InvalidNumber(_)
+            | InvalidThrowError::Unparseable { .. },
+        ) => {}
+        Err(err) => panic!("unexpected error {:?} for {:?}", err, text),
+    }
+});
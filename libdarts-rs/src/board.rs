@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::throw::Throw;
+
+/// The throws that can be made on a board and what they score
+///
+/// Throws score their usual points unless a board overrides them with [DartBoard::with_score].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DartBoard {
+    valid_throws: Vec<Throw>,
+    scores: HashMap<Throw, u8>,
+}
+
+impl DartBoard {
+    /// A board that only allows the given throws
+    pub fn new(valid_throws: Vec<Throw>) -> Self {
+        DartBoard {
+            valid_throws,
+            scores: HashMap::new(),
+        }
+    }
+
+    /// The classic board with the numbers 1 to 20 and the bullseye
+    pub fn standard() -> Self {
+        Self::new(Throw::all_throws().collect())
+    }
+
+    /// Let the throw score the given points instead of its usual points
+    ///
+    /// The throw is added to the valid throws if it is not already one of them.
+    pub fn with_score(mut self, throw: Throw, points: u8) -> Self {
+        if !self.valid_throws.contains(&throw) {
+            self.valid_throws.push(throw.clone());
+        }
+
+        self.scores.insert(throw, points);
+        self
+    }
+
+    pub fn valid_throws(&self) -> &[Throw] {
+        &self.valid_throws
+    }
+
+    /// The points the throw scores on this board, `None` if it cannot be thrown
+    pub fn score_for(&self, throw: &Throw) -> Option<u8> {
+        if !self.valid_throws.contains(throw) {
+            return None;
+        }
+
+        Some(self.scores.get(throw).copied().unwrap_or(throw.points()))
+    }
+}
+
+impl Default for DartBoard {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_board_scores_usual_points() {
+        let board = DartBoard::standard();
+
+        assert_eq!(board.valid_throws().len(), 63);
+        assert_eq!(board.score_for(&Throw::triple(20).unwrap()), Some(60));
+        assert_eq!(board.score_for(&Throw::double_bull().unwrap()), Some(50));
+    }
+
+    #[test]
+    fn custom_board_restricts_throws_and_scores() {
+        let singles = (1..=20)
+            .map(|number| Throw::single(number).unwrap())
+            .collect();
+        let board = DartBoard::new(singles).with_score(Throw::single_bull().unwrap(), 30);
+
+        assert_eq!(board.score_for(&Throw::triple(20).unwrap()), None);
+        assert_eq!(board.score_for(&Throw::single(20).unwrap()), Some(20));
+        assert_eq!(board.score_for(&Throw::single_bull().unwrap()), Some(30));
+        assert_eq!(
+            Throw::all_valid(Some(&board)).len(),
+            board.valid_throws().len()
+        );
+    }
+}
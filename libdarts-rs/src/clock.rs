@@ -0,0 +1,22 @@
+use std::{
+    fmt::Debug,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Source of the current time, used to measure how long turns take
+pub trait Clock: Debug {
+    /// Time elapsed since a fixed point in the past
+    fn now(&self) -> Duration;
+}
+
+/// [Clock] reading the system time
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
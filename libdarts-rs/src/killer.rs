@@ -0,0 +1,321 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{
+    player::Player,
+    throw::{Multiplier, Throw},
+    turn::MAX_THROWS,
+};
+
+/// The lives every player starts with in a standard game
+pub const DEFAULT_LIVES: u8 = 5;
+
+/// Hits on their own number a player needs to become a killer
+pub const HITS_TO_BECOME_KILLER: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CreateKillerError {
+    /// Killer needs at least two players
+    NotEnoughPlayers,
+    /// Numbers have to be between 1 and 20
+    InvalidNumber(u8),
+    /// The number was given to more than one player
+    DuplicateNumber(u8),
+    /// Players have to start with at least one life
+    NoLives,
+}
+
+impl Error for CreateKillerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for CreateKillerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateKillerError::NotEnoughPlayers => writeln!(f, "Killer needs at least two players"),
+            CreateKillerError::InvalidNumber(n) => {
+                writeln!(f, "{} is not a number on the board", n)
+            }
+            CreateKillerError::DuplicateNumber(n) => writeln!(f, "Number {} is used twice", n),
+            CreateKillerError::NoLives => writeln!(f, "Players need at least one life"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum KillerError {
+    /// Only one player is left
+    Finished,
+}
+
+impl Error for KillerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for KillerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KillerError::Finished => writeln!(f, "Game is already finished"),
+        }
+    }
+}
+
+/// A player of a [KillerGame] with their number
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KillerPlayer {
+    pub player: Player,
+    pub number: u8,
+    pub lives: u8,
+    /// Hits on their own number, doubles and triples count two and three hits
+    pub hits: u8,
+    /// Set once the player hit their own number [HITS_TO_BECOME_KILLER] times
+    pub killer: bool,
+}
+
+/// The Killer game
+///
+/// Every player owns a number and becomes a killer by hitting it three times. A killer takes
+/// a life from an opponent by hitting the opponent's number, hitting their own number costs
+/// them a life. Players without lives are eliminated, the last player standing wins.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KillerGame {
+    players: Vec<KillerPlayer>,
+    current: usize,
+    throws_in_turn: usize,
+}
+
+impl KillerGame {
+    /// Create a game where every player starts with [DEFAULT_LIVES]
+    pub fn with_default_lives(players: &[(Player, u8)]) -> Result<Self, CreateKillerError> {
+        Self::new(players, DEFAULT_LIVES)
+    }
+
+    /// Create a game where every player starts with the given number of lives
+    pub fn new(players: &[(Player, u8)], lives: u8) -> Result<Self, CreateKillerError> {
+        if players.len() < 2 {
+            return Err(CreateKillerError::NotEnoughPlayers);
+        }
+
+        if lives == 0 {
+            return Err(CreateKillerError::NoLives);
+        }
+
+        for (index, (_, number)) in players.iter().enumerate() {
+            if !Throw::is_valid_number(*number) {
+                return Err(CreateKillerError::InvalidNumber(*number));
+            }
+
+            if players[..index].iter().any(|(_, other)| other == number) {
+                return Err(CreateKillerError::DuplicateNumber(*number));
+            }
+        }
+
+        Ok(KillerGame {
+            players: players
+                .iter()
+                .map(|(player, number)| KillerPlayer {
+                    player: player.clone(),
+                    number: *number,
+                    lives,
+                    hits: 0,
+                    killer: false,
+                })
+                .collect(),
+            current: 0,
+            throws_in_turn: 0,
+        })
+    }
+
+    pub fn players(&self) -> &[KillerPlayer] {
+        &self.players
+    }
+
+    /// Index of the player throwing next
+    pub fn current_player(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_eliminated(&self, player_index: usize) -> bool {
+        self.players[player_index].lives == 0
+    }
+
+    /// The last player standing
+    pub fn winner(&self) -> Option<usize> {
+        let mut alive = (0..self.players.len()).filter(|&index| !self.is_eliminated(index));
+
+        match (alive.next(), alive.next()) {
+            (Some(winner), None) => Some(winner),
+            _ => None,
+        }
+    }
+
+    pub fn add_throw(&mut self, throw: Throw) -> Result<(), KillerError> {
+        if self.winner().is_some() {
+            return Err(KillerError::Finished);
+        }
+
+        let current = self.current;
+        let hit = throw
+            .segment()
+            .and_then(|segment| self.players.iter().position(|p| p.number == segment));
+
+        if self.players[current].killer {
+            if let Some(target) = hit {
+                let target = &mut self.players[target];
+                target.lives = target.lives.saturating_sub(1);
+            }
+        } else if hit == Some(current) {
+            let marks = match throw.multiplier() {
+                Some(Multiplier::Triple) => 3,
+                Some(Multiplier::Double) => 2,
+                _ => 1,
+            };
+            let player = &mut self.players[current];
+
+            player.hits = player.hits.saturating_add(marks);
+            player.killer = player.hits >= HITS_TO_BECOME_KILLER;
+        }
+
+        self.throws_in_turn += 1;
+
+        if self.throws_in_turn == MAX_THROWS || self.is_eliminated(current) {
+            self.next_turn();
+        }
+
+        Ok(())
+    }
+
+    fn next_turn(&mut self) {
+        let count = self.players.len();
+
+        self.throws_in_turn = 0;
+        self.current = (1..=count)
+            .map(|offset| (self.current + offset) % count)
+            .find(|&index| !self.is_eliminated(index))
+            .unwrap_or(self.current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(lives: u8) -> KillerGame {
+        let players = [
+            (Player::new("Anna").unwrap(), 20),
+            (Player::new("Pete").unwrap(), 19),
+        ];
+
+        KillerGame::new(&players, lives).unwrap()
+    }
+
+    fn throw(notation: &str) -> Throw {
+        Throw::from_str(notation).unwrap()
+    }
+
+    #[test]
+    fn hitting_own_number_three_times_makes_killer() {
+        let mut game = game(3);
+
+        game.add_throw(throw("20")).unwrap();
+        game.add_throw(throw("20")).unwrap();
+        assert!(!game.players()[0].killer);
+        assert_eq!(game.players()[0].hits, 2);
+
+        game.add_throw(throw("20")).unwrap();
+        assert!(game.players()[0].killer);
+        assert_eq!(game.players()[0].lives, 3);
+        assert_eq!(game.current_player(), 1);
+    }
+
+    #[test]
+    fn doubles_and_triples_count_as_several_hits() {
+        let mut game = game(3);
+
+        game.add_throw(throw("D20")).unwrap();
+        assert!(!game.players()[0].killer);
+
+        game.add_throw(throw("20")).unwrap();
+        assert!(game.players()[0].killer);
+
+        for notation in ["0", "T19"] {
+            game.add_throw(throw(notation)).unwrap();
+        }
+        assert!(game.players()[1].killer);
+    }
+
+    #[test]
+    fn players_start_with_five_lives_by_default() {
+        let players = [
+            (Player::new("Anna").unwrap(), 20),
+            (Player::new("Pete").unwrap(), 19),
+        ];
+
+        let game = KillerGame::with_default_lives(&players).unwrap();
+
+        assert!(game.players().iter().all(|player| player.lives == 5));
+    }
+
+    #[test]
+    fn killer_eliminates_opponent() {
+        let mut game = game(2);
+
+        for notation in ["T20", "T19", "19"] {
+            game.add_throw(throw(notation)).unwrap();
+        }
+
+        assert!(game.is_eliminated(1));
+        assert_eq!(game.winner(), Some(0));
+        assert_eq!(game.add_throw(throw("19")), Err(KillerError::Finished));
+    }
+
+    #[test]
+    fn opponents_are_not_hit_before_becoming_killer() {
+        let mut game = game(1);
+
+        game.add_throw(throw("19")).unwrap();
+
+        assert!(!game.is_eliminated(1));
+        assert_eq!(game.winner(), None);
+    }
+
+    #[test]
+    fn numbers_must_be_valid_and_unique() {
+        let anna = Player::new("Anna").unwrap();
+        let pete = Player::new("Pete").unwrap();
+
+        assert_eq!(
+            KillerGame::new(&[(anna.clone(), 20), (pete.clone(), 20)], 3),
+            Err(CreateKillerError::DuplicateNumber(20))
+        );
+        assert_eq!(
+            KillerGame::new(&[(anna.clone(), 25), (pete, 20)], 3),
+            Err(CreateKillerError::InvalidNumber(25))
+        );
+        assert_eq!(
+            KillerGame::new(&[(anna, 20)], 3),
+            Err(CreateKillerError::NotEnoughPlayers)
+        );
+    }
+}
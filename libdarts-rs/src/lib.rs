@@ -1,4 +1,8 @@
+pub mod board;
+pub mod clock;
+pub mod killer;
 pub mod player;
+pub mod target_practice;
 pub mod throw;
 pub mod turn;
 pub mod x01;
@@ -26,7 +26,8 @@ impl std::fmt::Display for NewPlayerError {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
     name: String,
 }
@@ -41,6 +42,12 @@ impl Player {
         }
     }
 
+    /// Create a player after trimming the name and collapsing runs of whitespace into one space
+    pub fn new_from_str(name: &str) -> Result<Player, NewPlayerError> {
+        Player::new(&name.split_whitespace().collect::<Vec<_>>().join(" "))
+            .map_err(|_| NewPlayerError::InvalidName(name.into()))
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -79,4 +86,16 @@ mod tests {
 
         assert_eq!(Err(NewPlayerError::InvalidName(empty_name)), player);
     }
+
+    #[test]
+    fn names_are_normalized() {
+        let player = Player::new_from_str("  Anna \t Lena  ").unwrap();
+
+        assert_eq!(player.name(), "Anna Lena");
+        assert_eq!(
+            Player::new_from_str("   "),
+            Err(NewPlayerError::InvalidName(String::from("   ")))
+        );
+        assert_eq!(Player::new("  Anna  ").unwrap().name(), "  Anna  ");
+    }
 }
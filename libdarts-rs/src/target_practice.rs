@@ -0,0 +1,201 @@
+use std::{error::Error, fmt::Display};
+
+use crate::throw::Throw;
+
+/// Points for hitting the target exactly
+pub const EXACT_HIT_POINTS: u32 = 3;
+/// Points for hitting the segment of the target with the wrong multiplier
+pub const SEGMENT_HIT_POINTS: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CreateTargetPracticeError {
+    NoTargets,
+    NoDarts,
+}
+
+impl Error for CreateTargetPracticeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for CreateTargetPracticeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateTargetPracticeError::NoTargets => writeln!(f, "No targets given"),
+            CreateTargetPracticeError::NoDarts => writeln!(f, "Dart budget must not be zero"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TargetPracticeError {
+    /// All targets were hit or all darts were used
+    Finished,
+}
+
+impl Error for TargetPracticeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for TargetPracticeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetPracticeError::Finished => writeln!(f, "Practice is already finished"),
+        }
+    }
+}
+
+/// Practice game where a sequence of targets has to be hit with a limited number of darts
+///
+/// Every dart is thrown at the current target. Hitting the target exactly scores
+/// [EXACT_HIT_POINTS], hitting its segment with another multiplier scores
+/// [SEGMENT_HIT_POINTS]. Both advance to the next target, a miss scores nothing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetPractice {
+    targets: Vec<Throw>,
+    darts: usize,
+    throws: Vec<Throw>,
+    current_target: usize,
+    score: u32,
+}
+
+impl TargetPractice {
+    pub fn new(targets: Vec<Throw>, darts: usize) -> Result<Self, CreateTargetPracticeError> {
+        if targets.is_empty() {
+            Err(CreateTargetPracticeError::NoTargets)
+        } else if darts == 0 {
+            Err(CreateTargetPracticeError::NoDarts)
+        } else {
+            Ok(TargetPractice {
+                targets,
+                darts,
+                throws: vec![],
+                current_target: 0,
+                score: 0,
+            })
+        }
+    }
+
+    /// The target the next dart has to hit, None if the practice is finished
+    pub fn current_target(&self) -> Option<&Throw> {
+        if self.is_finished() {
+            None
+        } else {
+            self.targets.get(self.current_target)
+        }
+    }
+
+    /// Throw a dart at the current target and get the points it scored
+    pub fn add_throw(&mut self, throw: Throw) -> Result<u32, TargetPracticeError> {
+        let target = self.current_target().ok_or(TargetPracticeError::Finished)?;
+
+        let points = if *target == throw {
+            EXACT_HIT_POINTS
+        } else if throw.segment().is_some() && target.segment() == throw.segment() {
+            SEGMENT_HIT_POINTS
+        } else {
+            0
+        };
+
+        if points > 0 {
+            self.current_target += 1;
+        }
+
+        self.score += points;
+        self.throws.push(throw);
+
+        Ok(points)
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn darts_left(&self) -> usize {
+        self.darts - self.throws.len()
+    }
+
+    /// The practice is finished once every target was hit or no darts are left
+    pub fn is_finished(&self) -> bool {
+        self.current_target >= self.targets.len() || self.darts_left() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn practice(darts: usize) -> TargetPractice {
+        let targets = vec![Throw::double(20).unwrap(), Throw::triple(19).unwrap()];
+
+        TargetPractice::new(targets, darts).unwrap()
+    }
+
+    #[test]
+    fn exact_hit_scores_most_and_advances() {
+        let mut practice = practice(5);
+
+        assert_eq!(practice.add_throw(Throw::double(20).unwrap()), Ok(3));
+        assert_eq!(practice.score(), EXACT_HIT_POINTS);
+        assert_eq!(practice.current_target(), Throw::triple(19).ok().as_ref());
+    }
+
+    #[test]
+    fn wrong_multiplier_scores_less() {
+        let mut practice = practice(5);
+
+        assert_eq!(practice.add_throw(Throw::single(20).unwrap()), Ok(1));
+        assert_eq!(practice.add_throw(Throw::miss().unwrap()), Ok(0));
+        assert_eq!(practice.add_throw(Throw::triple(19).unwrap()), Ok(3));
+
+        assert_eq!(practice.score(), SEGMENT_HIT_POINTS + EXACT_HIT_POINTS);
+        assert!(practice.is_finished());
+    }
+
+    #[test]
+    fn practice_ends_when_darts_are_used() {
+        let mut practice = practice(2);
+
+        practice.add_throw(Throw::single(5).unwrap()).unwrap();
+        practice.add_throw(Throw::miss().unwrap()).unwrap();
+
+        assert!(practice.is_finished());
+        assert_eq!(practice.score(), 0);
+        assert_eq!(
+            practice.add_throw(Throw::double(20).unwrap()),
+            Err(TargetPracticeError::Finished)
+        );
+    }
+
+    #[test]
+    fn practice_needs_targets_and_darts() {
+        assert_eq!(
+            TargetPractice::new(vec![], 3),
+            Err(CreateTargetPracticeError::NoTargets)
+        );
+        assert_eq!(
+            TargetPractice::new(vec![Throw::single_bull().unwrap()], 0),
+            Err(CreateTargetPracticeError::NoDarts)
+        );
+    }
+}
@@ -1,5 +1,10 @@
+use std::num::ParseIntError;
+
+use crate::{board::DartBoard, x01::ruleset::OutRule};
+
 /// Represents the score multiplier region of a [Throw]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Multiplier {
     Single,
     Double,
@@ -15,8 +20,25 @@ impl Multiplier {
             Multiplier::Triple => 3,
         }
     }
+
+    /// Get the prefix used for the multiplier in the short notation of a throw
+    fn prefix(&self) -> &'static str {
+        match self {
+            Multiplier::Single => "",
+            Multiplier::Double => "D",
+            Multiplier::Triple => "T",
+        }
+    }
 }
 
+/// Sum the points of the throws, e.g. of a whole turn history
+pub fn total_points(throws: &[Throw]) -> u32 {
+    throws.iter().map(|throw| u32::from(throw.points())).sum()
+}
+
+/// The byte of a board miss, see [Throw::to_byte]
+pub const BOARD_MISS_BYTE: u8 = 0b0011_1111;
+
 /// An error that might occur when using any of the methods to creat a throw
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InvalidThrowError {
@@ -24,8 +46,13 @@ pub enum InvalidThrowError {
     BullseyeTriple,
     /// Valid numbers are 1-20 inclusive
     InvalidNumber(u8),
-    // Error during parse
-    Unparseable(String),
+    /// The code is not a throw, see [Throw::from_code] and [Throw::from_byte]
+    InvalidCode(u16),
+    /// The text is no throw, `source` holds the error if the number could not be parsed
+    Unparseable {
+        text: String,
+        source: Option<ParseIntError>,
+    },
 }
 
 impl std::fmt::Display for InvalidThrowError {
@@ -33,14 +60,20 @@ impl std::fmt::Display for InvalidThrowError {
         match self {
             InvalidThrowError::BullseyeTriple => writeln!(f, "Bullseye cannot be a triple"),
             InvalidThrowError::InvalidNumber(val) => writeln!(f, "Throw has invalid value {val}"),
-            InvalidThrowError::Unparseable(text) => writeln!(f, "Could not parse {}", text),
+            InvalidThrowError::InvalidCode(code) => writeln!(f, "{code} is no throw code"),
+            InvalidThrowError::Unparseable { text, .. } => writeln!(f, "Could not parse {}", text),
         }
     }
 }
 
 impl std::error::Error for InvalidThrowError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            InvalidThrowError::Unparseable {
+                source: Some(err), ..
+            } => Some(err),
+            _ => None,
+        }
     }
 
     fn description(&self) -> &str {
@@ -55,15 +88,54 @@ impl std::error::Error for InvalidThrowError {
 /// Typedef for the return value of the various creation methods of throws
 pub type ThrowResult = Result<Throw, InvalidThrowError>;
 
+/// The numbered sectors of the dart board in clockwise order, starting at the top
+pub const BOARD_ORDER: [u8; 20] = [
+    20, 1, 18, 4, 13, 6, 10, 15, 2, 17, 3, 19, 7, 16, 8, 11, 14, 9, 12, 5,
+];
+
 /// Represents a single throw on the dart board
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Throw {
     /// The inner two rings of the dartboard, Multiplier indicates inner or outer bullseye
     Bullseye(Multiplier),
     /// One of the twenty numbers with multiplier
     Number(Multiplier, u8),
-    /// Didn't hit any scoring region or the board at all
+    /// Didn't hit the board at all
     Miss,
+    /// Hit the board but no scoring region (e.g. registered by an electronic board)
+    BoardMiss,
+}
+
+impl Default for Throw {
+    /// A throw defaults to a miss
+    fn default() -> Self {
+        Throw::Miss
+    }
+}
+
+/// Create a throw from a multiplier and a number, 25 is the bullseye
+impl TryFrom<(Multiplier, u8)> for Throw {
+    type Error = InvalidThrowError;
+
+    fn try_from((multiplier, number): (Multiplier, u8)) -> ThrowResult {
+        match number {
+            25 => Throw::bullseye(multiplier),
+            number => Throw::number(multiplier, number),
+        }
+    }
+}
+
+/// Create a single throw from a number, 25 is the single bull and 0 a miss
+impl TryFrom<u8> for Throw {
+    type Error = InvalidThrowError;
+
+    fn try_from(number: u8) -> ThrowResult {
+        match number {
+            0 => Throw::miss(),
+            number => Throw::try_from((Multiplier::Single, number)),
+        }
+    }
 }
 
 impl Throw {
@@ -75,10 +147,20 @@ impl Throw {
         }
     }
 
+    /// Check if the number is one of the numbers 1-20 of the board
+    pub fn is_valid_number(number: u8) -> bool {
+        (1..=20).contains(&number)
+    }
+
+    /// Check if the sector is one of the numbers 1-20 or 25 for the bullseye
+    pub fn is_valid_sector(sector: u8) -> bool {
+        Self::is_valid_number(sector) || sector == 25
+    }
+
     /// Create a new number 1-20 throw.
     pub fn number(multiplier: Multiplier, number: u8) -> ThrowResult {
         match number {
-            number if (1u8..21u8).contains(&number) => Ok(Throw::Number(multiplier, number)),
+            number if Self::is_valid_number(number) => Ok(Throw::Number(multiplier, number)),
             number => Err(InvalidThrowError::InvalidNumber(number)),
         }
     }
@@ -100,11 +182,54 @@ impl Throw {
         Self::number(Multiplier::Triple, number)
     }
 
+    /// Create a new single bullseye throw
+    ///
+    /// Calls [Throw::bullseye(Multiplier::Single)]
+    pub fn single_bull() -> ThrowResult {
+        Self::bullseye(Multiplier::Single)
+    }
+
+    /// Create a new double bullseye throw
+    ///
+    /// Calls [Throw::bullseye(Multiplier::Double)]
+    pub fn double_bull() -> ThrowResult {
+        Self::bullseye(Multiplier::Double)
+    }
+
     /// Create a missed throw
     pub fn miss() -> ThrowResult {
         Ok(Throw::Miss)
     }
 
+    /// Create a throw that hit the board without scoring
+    pub fn board_miss() -> ThrowResult {
+        Ok(Throw::BoardMiss)
+    }
+
+    /// Iterate over every valid throw: all numbers with every multiplier, single and
+    /// double bullseye and the miss
+    ///
+    /// [Throw::BoardMiss] is left out since it scores the same as the miss.
+    pub fn all_throws() -> impl Iterator<Item = Throw> {
+        let numbers = [Multiplier::Single, Multiplier::Double, Multiplier::Triple]
+            .into_iter()
+            .flat_map(|mult| (1..=20).map(move |number| Throw::Number(mult, number)));
+
+        let bullseyes = [Multiplier::Single, Multiplier::Double]
+            .into_iter()
+            .map(Throw::Bullseye);
+
+        numbers.chain(bullseyes).chain(std::iter::once(Throw::Miss))
+    }
+
+    /// Every throw that is valid on the board, the standard board if none is given
+    pub fn all_valid(board: Option<&DartBoard>) -> Vec<Throw> {
+        match board {
+            Some(board) => board.valid_throws().to_vec(),
+            None => Throw::all_throws().collect(),
+        }
+    }
+
     fn parse_multiplier(ch: &char) -> Option<Multiplier> {
         match ch {
             'd' | 'D' => Some(Multiplier::Double),
@@ -113,11 +238,29 @@ impl Throw {
         }
     }
 
+    /// Parse a throw from its notation, see [Throw::notation]
+    ///
+    /// Besides the notation the bull can be given as `bull` or `25` for the single bull
+    /// and `dbull`, `bullseye` or `50` for the double bull, ignoring case.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(text: &str) -> ThrowResult {
+        if text.eq_ignore_ascii_case("m") {
+            return Ok(Throw::BoardMiss);
+        }
+
+        match text.to_ascii_lowercase().as_str() {
+            "bull" => return Throw::single_bull(),
+            "dbull" | "bullseye" | "50" => return Throw::double_bull(),
+            _ => {}
+        }
+
         let mut chars = text.chars().peekable();
 
         match chars.peek() {
-            None => return Err(InvalidThrowError::Unparseable(text.into())),
+            None => Err(InvalidThrowError::Unparseable {
+                text: text.into(),
+                source: None,
+            }),
             Some(ch) => {
                 let opt_mult = Self::parse_multiplier(ch);
 
@@ -129,27 +272,140 @@ impl Throw {
                     None => Multiplier::Single,
                 };
 
-                let number = chars.collect::<String>().parse::<u8>().ok();
+                let number = chars.collect::<String>().parse::<u8>();
 
                 match number {
-                    Some(n) if (1..21).contains(&n) => Ok(Throw::Number(mult, n)),
-                    Some(n) if n == 25 => Throw::bullseye(mult),
-                    Some(n) if n == 0 => Ok(Throw::Miss),
-                    _ => Err(InvalidThrowError::Unparseable(text.into())),
+                    Ok(n) if Self::is_valid_number(n) => Ok(Throw::Number(mult, n)),
+                    Ok(25) => Throw::bullseye(mult),
+                    Ok(0) => Ok(Throw::Miss),
+                    Ok(_) => Err(InvalidThrowError::Unparseable {
+                        text: text.into(),
+                        source: None,
+                    }),
+                    Err(err) => Err(InvalidThrowError::Unparseable {
+                        text: text.into(),
+                        source: Some(err),
+                    }),
                 }
             }
         }
     }
 
+    /// Decode a throw from its compact code, see [Throw::to_code]
+    pub fn from_code(code: u16) -> ThrowResult {
+        match code {
+            0 => Ok(Throw::Miss),
+            1..=20 => Throw::single(code as u8),
+            21..=40 => Throw::double((code - 20) as u8),
+            41..=60 => Throw::triple((code - 40) as u8),
+            61 => Throw::single_bull(),
+            62 => Throw::double_bull(),
+            63 => Ok(Throw::BoardMiss),
+            code => Err(InvalidThrowError::InvalidCode(code)),
+        }
+    }
+
+    /// Encode the throw as a number between 0 and 63
+    ///
+    /// 0 is a miss, 1-20 are singles, 21-40 doubles and 41-60 triples of 1-20,
+    /// 61 is the single bull, 62 the double bull and 63 a board miss.
+    pub fn to_code(&self) -> u16 {
+        match self {
+            Throw::Miss => 0,
+            Throw::BoardMiss => 63,
+            Throw::Number(mult, number) => {
+                let offset = match mult {
+                    Multiplier::Single => 0,
+                    Multiplier::Double => 20,
+                    Multiplier::Triple => 40,
+                };
+
+                offset + u16::from(*number)
+            }
+            Throw::Bullseye(Multiplier::Double) => 62,
+            Throw::Bullseye(_) => 61,
+        }
+    }
+
+    /// Encode the throw into a single byte
+    ///
+    /// The top two bits hold the multiplier (1 single, 2 double, 3 triple) and the low six
+    /// bits the segment, 25 for the bull. `0` is a miss and [BOARD_MISS_BYTE] a board miss.
+    pub fn to_byte(&self) -> u8 {
+        let multiplier_bits = |mult: &Multiplier| match mult {
+            Multiplier::Single => 1 << 6,
+            Multiplier::Double => 2 << 6,
+            Multiplier::Triple => 3 << 6,
+        };
+
+        match self {
+            Throw::Miss => 0,
+            Throw::BoardMiss => BOARD_MISS_BYTE,
+            Throw::Bullseye(mult) => multiplier_bits(mult) | 25,
+            Throw::Number(mult, number) => multiplier_bits(mult) | number,
+        }
+    }
+
+    /// Decode a throw from its byte, see [Throw::to_byte]
+    pub fn from_byte(byte: u8) -> ThrowResult {
+        let segment = byte & 0b0011_1111;
+        let multiplier = match byte >> 6 {
+            1 => Multiplier::Single,
+            2 => Multiplier::Double,
+            3 => Multiplier::Triple,
+            _ => {
+                return match byte {
+                    0 => Ok(Throw::Miss),
+                    BOARD_MISS_BYTE => Ok(Throw::BoardMiss),
+                    byte => Err(InvalidThrowError::InvalidCode(byte.into())),
+                }
+            }
+        };
+
+        Throw::try_from((multiplier, segment))
+    }
+
+    /// Check if the throw finishes the remaining points under the out rule
+    pub fn is_achievable_with_out_rule(&self, remaining: u32, out_rule: &OutRule) -> bool {
+        u32::from(self.points()) == remaining && out_rule.valid_finisher(self)
+    }
+
+    /// Lazily parse throws separated by whitespace or commas, e.g. `T20 D20, 1`
+    pub fn parse_sequence(input: &str) -> impl Iterator<Item = ThrowResult> + '_ {
+        input
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|text| !text.is_empty())
+            .map(Throw::from_str)
+    }
+
+    /// Get the short notation of the throw (e.g. `T20`, `D25`, `0` for a miss or `m` for a board miss)
+    ///
+    /// The notation can be parsed again with [Throw::from_str]
+    pub fn notation(&self) -> String {
+        match self {
+            Throw::Miss => String::from("0"),
+            Throw::BoardMiss => String::from("m"),
+            Throw::Bullseye(mult) => format!("{}25", mult.prefix()),
+            Throw::Number(mult, number) => format!("{}{}", mult.prefix(), number),
+        }
+    }
+
     /// Calculate the score of the throw.
     pub fn points(&self) -> u8 {
         match self {
-            Throw::Miss => 0,
+            Throw::Miss | Throw::BoardMiss => 0,
             Throw::Bullseye(mult) => 25 * mult.factor(),
             Throw::Number(mult, number) => mult.factor() * number,
         }
     }
 
+    /// Calculate the score of the throw as [u16]
+    ///
+    /// Prefer this when adding up several throws, so the sum cannot overflow.
+    pub fn points_u16(&self) -> u16 {
+        self.points().into()
+    }
+
     /// Get the multiplier if there is one
     ///
     /// # Returns
@@ -160,7 +416,53 @@ impl Throw {
         match self {
             Throw::Bullseye(mult) => Some(*mult),
             Throw::Number(mult, _) => Some(*mult),
-            Throw::Miss => None,
+            Throw::Miss | Throw::BoardMiss => None,
+        }
+    }
+
+    /// Get the segment that was hit, ignoring the multiplier
+    ///
+    /// The bullseye is segment 25, a miss has no segment.
+    pub fn segment(&self) -> Option<u8> {
+        match self {
+            Throw::Bullseye(_) => Some(25),
+            Throw::Number(_, number) => Some(*number),
+            Throw::Miss | Throw::BoardMiss => None,
+        }
+    }
+
+    /// Get the numbered sector of the board that was hit, 25 for the bullseye
+    ///
+    /// This is the same as [Throw::segment].
+    pub fn board_sector(&self) -> Option<u8> {
+        self.segment()
+    }
+
+    /// Get the base score of a sector of the board, 25 for the bullseye
+    pub fn sector_points(sector: u8) -> Result<u8, InvalidThrowError> {
+        if Self::is_valid_sector(sector) {
+            Ok(sector)
+        } else {
+            Err(InvalidThrowError::InvalidNumber(sector))
+        }
+    }
+
+    /// Get the sectors left and right of the hit sector on the board
+    ///
+    /// The bullseye is treated as if it was the top of the board, i.e. `(20, 1)`.
+    pub fn adjacent_sectors(&self) -> Option<(u8, u8)> {
+        match self {
+            Throw::Miss | Throw::BoardMiss => None,
+            Throw::Bullseye(_) => Some((BOARD_ORDER[0], BOARD_ORDER[1])),
+            Throw::Number(_, number) => {
+                let count = BOARD_ORDER.len();
+                let index = BOARD_ORDER.iter().position(|sector| sector == number)?;
+
+                Some((
+                    BOARD_ORDER[(index + count - 1) % count],
+                    BOARD_ORDER[(index + 1) % count],
+                ))
+            }
         }
     }
 }
@@ -198,6 +500,24 @@ mod tests {
         assert_eq!(score, 60);
     }
 
+    #[test]
+    fn points_u16_can_be_summed_without_overflow() {
+        let t20 = Throw::triple(20).unwrap();
+        let bull = Throw::double_bull().unwrap();
+
+        let sum: u16 = [&t20, &t20, &t20, &bull, &bull]
+            .iter()
+            .map(|throw| throw.points_u16())
+            .sum();
+
+        assert_eq!(sum, 280);
+    }
+
+    #[test]
+    fn default_throw_is_miss() {
+        assert_eq!(Throw::default(), Throw::Miss);
+    }
+
     #[test]
     fn miss_has_score_zero() {
         let score = Throw::miss().unwrap().points();
@@ -213,6 +533,30 @@ mod tests {
         assert_eq!(score, 50);
     }
 
+    #[test]
+    fn bull_constructors_create_bullseyes() {
+        assert_eq!(
+            Throw::single_bull(),
+            Ok(Throw::Bullseye(Multiplier::Single))
+        );
+        assert_eq!(
+            Throw::double_bull(),
+            Ok(Throw::Bullseye(Multiplier::Double))
+        );
+    }
+
+    #[test]
+    fn all_throws_contains_every_valid_throw() {
+        let throws: Vec<_> = Throw::all_throws().collect();
+
+        // 60 numbers, 2 bullseyes and the miss
+        assert_eq!(throws.len(), 63);
+        assert!(throws.contains(&Throw::triple(20).unwrap()));
+        assert!(throws.contains(&Throw::double_bull().unwrap()));
+        assert!(throws.contains(&Throw::Miss));
+        assert!(!throws.contains(&Throw::Bullseye(Multiplier::Triple)));
+    }
+
     #[test]
     fn test_parse_miss() {
         assert_eq!(Throw::miss(), Throw::from_str("0"));
@@ -248,4 +592,226 @@ mod tests {
             assert_eq!(Throw::triple(number), Throw::from_str(&string));
         }
     }
+
+    #[test]
+    fn notation_can_be_parsed_again() {
+        let throws = [
+            Throw::miss(),
+            Throw::single(1),
+            Throw::double(16),
+            Throw::triple(20),
+            Throw::bullseye(Multiplier::Single),
+            Throw::bullseye(Multiplier::Double),
+        ];
+
+        for throw in throws {
+            let throw = throw.unwrap();
+            assert_eq!(Throw::from_str(&throw.notation()), Ok(throw));
+        }
+    }
+
+    #[test]
+    fn throws_can_be_counted_in_hash_map() {
+        let mut counts = std::collections::HashMap::new();
+
+        for notation in ["T20", "1", "T20", "D20", "T20"] {
+            *counts
+                .entry(Throw::from_str(notation).unwrap())
+                .or_insert(0) += 1;
+        }
+
+        assert_eq!(counts[&Throw::triple(20).unwrap()], 3);
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn board_sector_ignores_multiplier() {
+        assert_eq!(Throw::triple(20).unwrap().board_sector(), Some(20));
+        assert_eq!(Throw::single(7).unwrap().board_sector(), Some(7));
+        assert_eq!(Throw::double_bull().unwrap().board_sector(), Some(25));
+        assert_eq!(Throw::miss().unwrap().board_sector(), None);
+    }
+
+    #[test]
+    fn throws_are_converted_from_numbers() {
+        assert_eq!(Throw::try_from((Multiplier::Triple, 20)), Throw::triple(20));
+        assert_eq!(
+            Throw::try_from((Multiplier::Double, 25)),
+            Throw::double_bull()
+        );
+        assert_eq!(Throw::try_from(25), Throw::single_bull());
+        assert_eq!(Throw::try_from(7), Throw::single(7));
+        assert_eq!(Throw::try_from(0), Throw::miss());
+    }
+
+    #[test]
+    fn invalid_numbers_are_not_converted() {
+        assert_eq!(
+            Throw::try_from((Multiplier::Triple, 25)),
+            Err(InvalidThrowError::BullseyeTriple)
+        );
+        assert_eq!(
+            Throw::try_from((Multiplier::Single, 21)),
+            Err(InvalidThrowError::InvalidNumber(21))
+        );
+        assert_eq!(
+            Throw::try_from((Multiplier::Double, 0)),
+            Err(InvalidThrowError::InvalidNumber(0))
+        );
+    }
+
+    #[test]
+    fn valid_numbers_and_sectors_are_checked() {
+        assert!(Throw::is_valid_number(1));
+        assert!(Throw::is_valid_number(20));
+        assert!(!Throw::is_valid_number(0));
+        assert!(!Throw::is_valid_number(21));
+        assert!(!Throw::is_valid_number(25));
+
+        assert!(Throw::is_valid_sector(20));
+        assert!(Throw::is_valid_sector(25));
+        assert!(!Throw::is_valid_sector(0));
+        assert!(!Throw::is_valid_sector(50));
+    }
+
+    #[test]
+    fn sequences_are_split_on_whitespace_and_commas() {
+        let throws: Vec<ThrowResult> = Throw::parse_sequence("T20 D20,1 ,  bull").collect();
+
+        assert_eq!(
+            throws,
+            [
+                Throw::triple(20),
+                Throw::double(20),
+                Throw::single(1),
+                Throw::single_bull()
+            ]
+        );
+
+        let mut throws = Throw::parse_sequence("T20 X");
+        assert!(throws.next().unwrap().is_ok());
+        assert!(throws.next().unwrap().is_err());
+        assert_eq!(throws.next(), None);
+    }
+
+    #[test]
+    fn bull_keywords_are_parsed() {
+        assert_eq!(Throw::from_str("bull"), Throw::single_bull());
+        assert_eq!(Throw::from_str("BULL"), Throw::single_bull());
+        assert_eq!(Throw::from_str("50"), Throw::double_bull());
+        assert_eq!(Throw::from_str("dbull"), Throw::double_bull());
+        assert_eq!(Throw::from_str("Bullseye"), Throw::double_bull());
+        assert_eq!(Throw::from_str("25"), Throw::single_bull());
+    }
+
+    #[test]
+    fn throw_codes_round_trip() {
+        for throw in Throw::all_throws().chain(std::iter::once(Throw::BoardMiss)) {
+            assert_eq!(Throw::from_code(throw.to_code()), Ok(throw));
+        }
+
+        assert_eq!(Throw::from_code(21), Throw::double(1));
+        assert_eq!(Throw::from_code(62), Throw::double_bull());
+        assert_eq!(Throw::BoardMiss.to_code(), 63);
+        assert_eq!(
+            Throw::from_code(64),
+            Err(InvalidThrowError::InvalidCode(64))
+        );
+    }
+
+    #[test]
+    fn throw_bytes_round_trip() {
+        for throw in Throw::all_throws().chain(std::iter::once(Throw::BoardMiss)) {
+            assert_eq!(Throw::from_byte(throw.to_byte()), Ok(throw));
+        }
+
+        assert_eq!(Throw::triple(20).unwrap().to_byte(), 0b1101_0100);
+        assert_eq!(
+            Throw::from_byte(0b1101_1001),
+            Err(InvalidThrowError::BullseyeTriple)
+        );
+        assert_eq!(Throw::from_byte(5), Err(InvalidThrowError::InvalidCode(5)));
+    }
+
+    #[test]
+    fn finishing_throws_respect_out_rule() {
+        let d20 = Throw::double(20).unwrap();
+        let s20 = Throw::single(20).unwrap();
+
+        assert!(d20.is_achievable_with_out_rule(40, &OutRule::Double));
+        assert!(!d20.is_achievable_with_out_rule(41, &OutRule::Double));
+        assert!(!s20.is_achievable_with_out_rule(20, &OutRule::Double));
+        assert!(s20.is_achievable_with_out_rule(20, &OutRule::Any));
+        assert!(!Throw::single_bull()
+            .unwrap()
+            .is_achievable_with_out_rule(25, &OutRule::Double));
+    }
+
+    #[test]
+    fn total_points_sum_up_throws() {
+        let maximum = vec![Throw::triple(20).unwrap(); 3];
+        assert_eq!(total_points(&maximum), 180);
+
+        let history = vec![Throw::triple(20).unwrap(); 9];
+        assert_eq!(total_points(&history), 540);
+        assert_eq!(total_points(&[]), 0);
+    }
+
+    #[test]
+    fn sector_points_are_looked_up() {
+        assert_eq!(Throw::sector_points(1), Ok(1));
+        assert_eq!(Throw::sector_points(20), Ok(20));
+        assert_eq!(Throw::sector_points(25), Ok(25));
+
+        for sector in [0, 21, 24, 50] {
+            assert_eq!(
+                Throw::sector_points(sector),
+                Err(InvalidThrowError::InvalidNumber(sector))
+            );
+        }
+    }
+
+    #[test]
+    fn adjacent_sectors_follow_the_board() {
+        assert_eq!(Throw::triple(20).unwrap().adjacent_sectors(), Some((5, 1)));
+        assert_eq!(Throw::single(5).unwrap().adjacent_sectors(), Some((12, 20)));
+        assert_eq!(Throw::double(3).unwrap().adjacent_sectors(), Some((17, 19)));
+        assert_eq!(
+            Throw::single_bull().unwrap().adjacent_sectors(),
+            Some((20, 1))
+        );
+        assert_eq!(Throw::miss().unwrap().adjacent_sectors(), None);
+    }
+
+    #[test]
+    fn board_miss_is_distinct_from_miss() {
+        let board_miss = Throw::from_str("m").unwrap();
+
+        assert_eq!(board_miss, Throw::BoardMiss);
+        assert_eq!(Throw::from_str("M"), Ok(Throw::BoardMiss));
+        assert_eq!(Throw::from_str("0"), Ok(Throw::Miss));
+        assert_ne!(board_miss, Throw::Miss);
+
+        assert_eq!(board_miss.points(), Throw::Miss.points());
+        assert_eq!(board_miss.multiplier(), None);
+        assert_eq!(Throw::from_str(&board_miss.notation()), Ok(board_miss));
+    }
+
+    #[test]
+    fn parse_errors_keep_their_source() {
+        use std::error::Error;
+
+        let err = Throw::from_str("Tx").unwrap_err();
+        assert!(err.source().is_some());
+
+        let err = Throw::from_str("T21").unwrap_err();
+        assert_eq!(
+            err,
+            InvalidThrowError::Unparseable {
+                text: String::from("T21"),
+                source: None
+            }
+        );
+        assert!(err.source().is_none());
+    }
 }
@@ -1,44 +1,185 @@
+use std::{error::Error, fmt::Display};
+
 use super::throw::*;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Turn {
     throws: Vec<Throw>,
+    /// Indices of throws that were made but score nothing
+    #[cfg_attr(feature = "serde", serde(default))]
+    ignored: Vec<usize>,
     bust: bool,
+    forfeited: bool,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ThrowError {
     Bust,
+    Forfeited,
+}
+
+impl Error for ThrowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for ThrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThrowError::Bust => writeln!(f, "Turn is bust"),
+            ThrowError::Forfeited => writeln!(f, "Turn was forfeited"),
+        }
+    }
 }
 
 pub type ThrowResult = Result<(), ThrowError>;
 
+/// The maximum number of throws in a turn
+pub const MAX_THROWS: usize = 3;
+
+/// An error that might occur when creating a turn with [Turn::from_throws]
+#[derive(Debug, PartialEq, Eq)]
+pub enum TurnBuildError {
+    /// A turn has at most three throws
+    TooManyThrows(usize),
+    /// A turn can only be a bust if a throw was made
+    BustWithoutThrows,
+}
+
+impl Error for TurnBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for TurnBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TurnBuildError::TooManyThrows(n) => {
+                writeln!(f, "A turn cannot have {} throws", n)
+            }
+            TurnBuildError::BustWithoutThrows => writeln!(f, "A turn without throws cannot bust"),
+        }
+    }
+}
+
+/// Named score brackets of a turn
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TurnCategory {
+    /// Less than 100 points
+    Low,
+    /// Exactly 100 points
+    Ton,
+    /// More than 100 points, carries the points above 100 (e.g. 40 for a "ton-40")
+    TonPlus(u8),
+    /// 180 points
+    Maximum,
+    Bust,
+}
+
+/// Scores celebrated by commentators, see [Turn::high_score_classification]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HighScore {
+    /// 180 points
+    Maximum,
+    /// 140 to 179 points
+    TonForty,
+    /// 101 to 139 points
+    TonPlus,
+    /// Exactly 100 points
+    Ton,
+}
+
 impl Turn {
     pub fn new() -> Self {
         Turn {
             throws: vec![],
+            ignored: vec![],
             bust: false,
+            forfeited: false,
+        }
+    }
+
+    /// Create a turn from its throws
+    pub fn from_throws(
+        throws: impl IntoIterator<Item = Throw>,
+        bust: bool,
+    ) -> Result<Turn, TurnBuildError> {
+        let throws: Vec<Throw> = throws.into_iter().collect();
+
+        if throws.len() > MAX_THROWS {
+            Err(TurnBuildError::TooManyThrows(throws.len()))
+        } else if bust && throws.is_empty() {
+            Err(TurnBuildError::BustWithoutThrows)
+        } else {
+            Ok(Turn {
+                throws,
+                ignored: vec![],
+                bust,
+                forfeited: false,
+            })
         }
     }
 
     pub fn add_throw(&mut self, throw: Throw) -> ThrowResult {
         if self.bust {
             Err(ThrowError::Bust)
+        } else if self.forfeited {
+            Err(ThrowError::Forfeited)
         } else {
             self.throws.push(throw);
             Ok(())
         }
     }
 
+    /// Add a throw that was made but scores nothing, e.g. a dart that overshoots the remaining points
+    pub fn add_ignored_throw(&mut self, throw: Throw) -> ThrowResult {
+        self.add_throw(throw)?;
+        self.ignored.push(self.throws.len() - 1);
+        Ok(())
+    }
+
+    /// Check if the throw at the index was made but scores nothing
+    pub fn is_ignored(&self, index: usize) -> bool {
+        self.ignored.contains(&index)
+    }
+
+    pub fn throws(&self) -> &[Throw] {
+        &self.throws
+    }
+
     pub fn num_throws(&self) -> usize {
         self.throws.len()
     }
 
     pub fn points(&self) -> u8 {
-        if self.bust {
+        if self.bust || self.forfeited {
             0
         } else {
-            self.throws.iter().map(|t| t.points()).sum()
+            self.throws
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !self.is_ignored(*index))
+                .map(|(_, t)| t.points())
+                .sum()
         }
     }
 
@@ -49,12 +190,106 @@ impl Turn {
     pub fn is_bust(&self) -> bool {
         self.bust
     }
+
+    /// End the turn without scoring, any throws already made are kept but score nothing
+    pub fn forfeit(&mut self) {
+        self.forfeited = true;
+    }
+
+    pub fn is_forfeited(&self) -> bool {
+        self.forfeited
+    }
+
+    pub fn category(&self) -> TurnCategory {
+        match self.points() {
+            _ if self.bust => TurnCategory::Bust,
+            180 => TurnCategory::Maximum,
+            100 => TurnCategory::Ton,
+            points if points > 100 => TurnCategory::TonPlus(points - 100),
+            _ => TurnCategory::Low,
+        }
+    }
+
+    /// Check if the turn is the maximum of three triple 20s
+    pub fn is_max_possible(&self) -> bool {
+        let triple_20 = Throw::Number(Multiplier::Triple, 20);
+
+        !self.bust
+            && !self.forfeited
+            && self.ignored.is_empty()
+            && self.throws.len() == MAX_THROWS
+            && self.throws.iter().all(|throw| *throw == triple_20)
+    }
+
+    /// Classify a turn of at least 100 points, bust turns are never a high score
+    pub fn high_score_classification(&self) -> Option<HighScore> {
+        match self.points() {
+            _ if self.bust => None,
+            180 => Some(HighScore::Maximum),
+            140..=179 => Some(HighScore::TonForty),
+            101..=139 => Some(HighScore::TonPlus),
+            100 => Some(HighScore::Ton),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Turn {
+    type Item = &'a Throw;
+    type IntoIter = std::slice::Iter<'a, Throw>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.throws.iter()
+    }
+}
+
+impl IntoIterator for Turn {
+    type Item = Throw;
+    type IntoIter = std::vec::IntoIter<Throw>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.throws.into_iter()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
+    fn any_throw() -> impl Strategy<Value = Throw> {
+        prop::sample::select(Throw::all_throws().collect::<Vec<_>>())
+    }
+
+    proptest! {
+        #[test]
+        fn busted_turn_scores_nothing(throws in prop::collection::vec(any_throw(), 1..=MAX_THROWS)) {
+            let mut turn = Turn::from_throws(throws, false).unwrap();
+            turn.bust();
+
+            prop_assert_eq!(turn.points(), 0);
+        }
+
+        #[test]
+        fn turn_scores_the_sum_of_its_throws(throws in prop::collection::vec(any_throw(), 0..=MAX_THROWS)) {
+            let sum: u32 = throws.iter().map(|throw| u32::from(throw.points())).sum();
+            let turn = Turn::from_throws(throws, false).unwrap();
+
+            prop_assert_eq!(u32::from(turn.points()), sum);
+        }
+
+        #[test]
+        fn busted_turn_takes_no_throws(
+            throws in prop::collection::vec(any_throw(), 1..=MAX_THROWS),
+            next in any_throw(),
+        ) {
+            let mut turn = Turn::from_throws(throws, true).unwrap();
+
+            prop_assert_eq!(turn.add_throw(next), Err(ThrowError::Bust));
+        }
+    }
+
     #[test]
     fn one_hundred_eiiiighty() {
         let mut round = Turn::new();
@@ -73,7 +308,7 @@ mod tests {
 
         turn.bust();
 
-        assert_eq!(turn.bust, true);
+        assert!(turn.bust);
     }
 
     #[test]
@@ -87,4 +322,130 @@ mod tests {
             Err(ThrowError::Bust)
         );
     }
+
+    #[test]
+    fn forfeited_turn_scores_nothing() {
+        let mut turn = Turn::new();
+        turn.add_throw(Throw::triple(20).unwrap()).unwrap();
+
+        turn.forfeit();
+
+        assert_eq!(turn.points(), 0);
+        assert_eq!(
+            turn.add_throw(Throw::triple(20).unwrap()),
+            Err(ThrowError::Forfeited)
+        );
+    }
+
+    #[test]
+    fn ignored_throw_is_kept_but_scores_nothing() {
+        let mut turn = Turn::new();
+        turn.add_throw(Throw::triple(20).unwrap()).unwrap();
+        turn.add_ignored_throw(Throw::triple(20).unwrap()).unwrap();
+        turn.add_throw(Throw::single(1).unwrap()).unwrap();
+
+        assert_eq!(turn.num_throws(), 3);
+        assert!(turn.is_ignored(1));
+        assert!(!turn.is_ignored(2));
+        assert_eq!(turn.points(), 61);
+    }
+
+    #[test]
+    fn turn_is_created_from_throws() {
+        let t20 = Throw::triple(20).unwrap();
+
+        let turn = Turn::from_throws([t20.clone(), t20.clone()], true).unwrap();
+
+        assert_eq!(turn.throws(), [t20.clone(), t20]);
+        assert!(turn.is_bust());
+    }
+
+    #[test]
+    fn turn_with_four_throws_cannot_be_created() {
+        let turn = Turn::from_throws(vec![Throw::Miss; 4], false);
+
+        assert_eq!(turn, Err(TurnBuildError::TooManyThrows(4)));
+    }
+
+    #[test]
+    fn empty_turn_cannot_be_bust() {
+        assert_eq!(
+            Turn::from_throws([], true),
+            Err(TurnBuildError::BustWithoutThrows)
+        );
+    }
+
+    #[test]
+    fn three_triple_20s_are_the_maximum() {
+        assert!(turn_of(&["T20", "T20", "T20"]).is_max_possible());
+        assert!(!turn_of(&["T20", "T20", "T19"]).is_max_possible());
+        assert!(!turn_of(&["T20", "T20"]).is_max_possible());
+
+        let mut bust = turn_of(&["T20", "T20", "T20"]);
+        bust.bust();
+        assert!(!bust.is_max_possible());
+    }
+
+    #[test]
+    fn turn_can_be_iterated() {
+        let turn = turn_of(&["T20", "D10"]);
+
+        let points: Vec<u8> = (&turn).into_iter().map(|throw| throw.points()).collect();
+        assert_eq!(points, [60, 20]);
+
+        let throws: Vec<Throw> = turn.into_iter().collect();
+        assert_eq!(
+            throws,
+            [Throw::triple(20).unwrap(), Throw::double(10).unwrap()]
+        );
+    }
+
+    fn turn_of(throws: &[&str]) -> Turn {
+        let mut turn = Turn::new();
+
+        for throw in throws {
+            turn.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        turn
+    }
+
+    #[test]
+    fn turns_are_categorized() {
+        assert_eq!(
+            turn_of(&["T20", "T20", "T20"]).category(),
+            TurnCategory::Maximum
+        );
+        assert_eq!(
+            turn_of(&["T20", "T20", "D10"]).category(),
+            TurnCategory::TonPlus(40)
+        );
+        assert_eq!(turn_of(&["T20", "D10", "15"]).category(), TurnCategory::Low);
+    }
+
+    #[test]
+    fn busted_turn_is_categorized_as_bust() {
+        let mut turn = turn_of(&["T20", "T20"]);
+        turn.bust();
+
+        assert_eq!(turn.category(), TurnCategory::Bust);
+    }
+
+    #[test]
+    fn high_scores_are_classified() {
+        let cases = [
+            (turn_of(&["T20", "T20", "T20"]), Some(HighScore::Maximum)),
+            (turn_of(&["T20", "T20", "T17"]), Some(HighScore::TonForty)),
+            (turn_of(&["T20", "T20", "1"]), Some(HighScore::TonPlus)),
+            (turn_of(&["T20", "D20"]), Some(HighScore::Ton)),
+            (turn_of(&["T20", "T13"]), None),
+        ];
+
+        for (turn, expected) in cases {
+            assert_eq!(turn.high_score_classification(), expected);
+        }
+
+        let bust = Turn::from_throws(vec![Throw::triple(20).unwrap(); 2], true).unwrap();
+        assert_eq!(bust.high_score_classification(), None);
+    }
 }
@@ -1,4 +1,8 @@
+pub mod checkout;
+pub mod game_match;
 pub mod leg;
+pub mod notation;
 pub mod participants;
 pub mod ruleset;
 pub mod set;
+pub mod stats;
@@ -0,0 +1,109 @@
+use crate::{throw::Throw, turn::MAX_THROWS};
+
+use super::ruleset::OutRule;
+
+/// Find every way to finish the remaining points with at most `darts_left` darts
+///
+/// The last throw of each checkout is a valid finisher for the out rule. The order of the
+/// darts before the finisher does not matter, so each combination of them is listed once.
+pub fn all_checkouts(remaining: u32, darts_left: u8, out_rule: &OutRule) -> Vec<Vec<Throw>> {
+    let scoring: Vec<Throw> = Throw::all_throws()
+        .filter(|throw| throw.points() > 0)
+        .collect();
+    let darts_left = (darts_left as usize).min(MAX_THROWS);
+
+    let mut checkouts = vec![];
+    let mut setup = vec![];
+    collect_checkouts(
+        &scoring,
+        0,
+        remaining,
+        darts_left,
+        out_rule,
+        &mut setup,
+        &mut checkouts,
+    );
+
+    checkouts
+}
+
+/// The number of distinct checkouts of the remaining points, see [all_checkouts]
+///
+/// The more ways there are to finish, the easier the finish is. Scores without
+/// any checkout (bogey numbers) have a difficulty of 0.
+pub fn difficulty(remaining: u32, darts_left: u8, out_rule: &OutRule) -> usize {
+    all_checkouts(remaining, darts_left, out_rule).len()
+}
+
+fn collect_checkouts(
+    scoring: &[Throw],
+    first_setup: usize,
+    remaining: u32,
+    darts_left: usize,
+    out_rule: &OutRule,
+    setup: &mut Vec<Throw>,
+    checkouts: &mut Vec<Vec<Throw>>,
+) {
+    if darts_left == 0 {
+        return;
+    }
+
+    for finisher in scoring {
+        if finisher.is_achievable_with_out_rule(remaining, out_rule) {
+            let mut checkout = setup.clone();
+            checkout.push(finisher.clone());
+            checkouts.push(checkout);
+        }
+    }
+
+    // Setup darts are only combined in the order of `scoring` to skip permutations
+    for (index, throw) in scoring.iter().enumerate().skip(first_setup) {
+        let points = u32::from(throw.points());
+
+        if points < remaining {
+            setup.push(throw.clone());
+            collect_checkouts(
+                scoring,
+                index,
+                remaining - points,
+                darts_left - 1,
+                out_rule,
+                setup,
+                checkouts,
+            );
+            setup.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn low_finishes_have_more_checkouts() {
+        let forty = difficulty(40, 3, &OutRule::Double);
+        let big_fish = difficulty(167, 3, &OutRule::Double);
+
+        assert!(big_fish > 0);
+        assert!(forty > big_fish);
+    }
+
+    #[test]
+    fn bogey_numbers_have_no_checkout() {
+        assert_eq!(difficulty(169, 3, &OutRule::Double), 0);
+        assert_eq!(difficulty(171, 3, &OutRule::Double), 0);
+    }
+
+    #[test]
+    fn checkouts_are_limited_by_darts_left() {
+        assert_eq!(
+            all_checkouts(40, 1, &OutRule::Double),
+            vec![vec![Throw::double(20).unwrap()]]
+        );
+        assert_eq!(difficulty(100, 1, &OutRule::Double), 0);
+        assert!(all_checkouts(100, 2, &OutRule::Double)
+            .iter()
+            .all(|checkout| checkout.len() == 2));
+    }
+}
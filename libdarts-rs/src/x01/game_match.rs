@@ -0,0 +1,662 @@
+#[cfg(feature = "serde")]
+use std::{error::Error, fmt::Display};
+
+use crate::throw::Throw;
+
+use super::{
+    leg::{Leg, LegError},
+    participants::Participants,
+    ruleset::{OvertimeRule, Ruleset},
+    set::{self, CreateSetError, Set},
+};
+#[cfg(feature = "serde")]
+use super::{
+    leg::{LegCheckpoint, RestoreLegError},
+    ruleset::RulesetValidationError,
+    set::{SetDocument, SetDocumentError},
+};
+
+/// A match of several sets, see [Ruleset::sets] for the format of the sets and
+/// [Ruleset::match_format] for the number of sets needed to win
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Match<'a> {
+    ruleset: &'a Ruleset,
+    participants: &'a Participants,
+    sets: Vec<Set<'a>>,
+    current_set: Set<'a>,
+    first_player: usize,
+    /// The leg deciding a level match, see [OvertimeRule::SuddenDeathLeg]
+    sudden_death: Option<Leg<'a>>,
+}
+
+/// Owned form of a match as exported by [Match::to_json]
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct MatchDocument {
+    pub ruleset: Ruleset,
+    pub participants: Participants,
+    sets: Vec<SetDocument>,
+    current_set: SetDocument,
+    first_player: usize,
+    sudden_death: Option<LegCheckpoint>,
+}
+
+/// An error that occurs when rebuilding a match with [MatchDocument::game_match]
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MatchDocumentError {
+    InvalidRuleset(Vec<RulesetValidationError>),
+    NoParticipants,
+    InvalidFirstPlayer(usize),
+    /// The set with the index was played with another ruleset or other participants
+    MismatchedSet(usize),
+    InvalidSet(usize, SetDocumentError),
+    /// The finished set with the index has no winner
+    UnfinishedSet(usize),
+    InvalidLeg(RestoreLegError),
+}
+
+#[cfg(feature = "serde")]
+impl Error for MatchDocumentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Display for MatchDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchDocumentError::InvalidRuleset(errors) => {
+                for err in errors {
+                    write!(f, "{}", err)?;
+                }
+                Ok(())
+            }
+            MatchDocumentError::NoParticipants => writeln!(f, "The match has no participants"),
+            MatchDocumentError::InvalidFirstPlayer(n) => {
+                writeln!(f, "First player '{}' is invalid.", n)
+            }
+            MatchDocumentError::MismatchedSet(n) => {
+                writeln!(f, "Set {} does not belong to the match", n)
+            }
+            MatchDocumentError::InvalidSet(_, err) => write!(f, "{}", err),
+            MatchDocumentError::UnfinishedSet(n) => writeln!(f, "Set {} was not finished", n),
+            MatchDocumentError::InvalidLeg(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl MatchDocument {
+    /// Parse a document created by [Match::to_json]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Rebuild the match described by the document
+    ///
+    /// Every set of the document has to repeat the ruleset and participants of the match.
+    pub fn game_match(&self) -> Result<Match<'_>, MatchDocumentError> {
+        let ruleset = &self.ruleset;
+        let participants = &self.participants;
+
+        ruleset
+            .validate()
+            .map_err(MatchDocumentError::InvalidRuleset)?;
+
+        if participants.count() == 0 {
+            return Err(MatchDocumentError::NoParticipants);
+        }
+
+        if self.first_player >= participants.count() {
+            return Err(MatchDocumentError::InvalidFirstPlayer(self.first_player));
+        }
+
+        let restore = |index: usize, document: &SetDocument| {
+            if document.ruleset != *ruleset || document.participants != *participants {
+                return Err(MatchDocumentError::MismatchedSet(index));
+            }
+
+            document
+                .set_with(ruleset, participants)
+                .map_err(|err| MatchDocumentError::InvalidSet(index, err))
+        };
+
+        let sets = self
+            .sets
+            .iter()
+            .enumerate()
+            .map(|(index, document)| restore(index, document))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(index) = sets.iter().position(|set| set.set_winner().is_none()) {
+            return Err(MatchDocumentError::UnfinishedSet(index));
+        }
+
+        let sudden_death = self
+            .sudden_death
+            .as_ref()
+            .map(|checkpoint| Leg::restore(ruleset, participants, checkpoint.clone()))
+            .transpose()
+            .map_err(MatchDocumentError::InvalidLeg)?;
+
+        Ok(Match {
+            ruleset,
+            participants,
+            current_set: restore(sets.len(), &self.current_set)?,
+            sets,
+            first_player: self.first_player,
+            sudden_death,
+        })
+    }
+}
+
+impl<'a> Match<'a> {
+    pub fn new(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        first_player: usize,
+    ) -> Result<Self, CreateSetError> {
+        Ok(Self {
+            ruleset,
+            participants,
+            sets: vec![],
+            current_set: Set::new(ruleset, participants, first_player)?,
+            first_player,
+            sudden_death: None,
+        })
+    }
+
+    pub fn current_set_number(&self) -> usize {
+        self.sets.len() + 1
+    }
+
+    pub fn current_set(&self) -> &Set<'a> {
+        &self.current_set
+    }
+
+    /// Export the complete match as JSON.
+    ///
+    /// The document contains the `ruleset`, the `participants`, the index of the
+    /// `first_player` of the current set, the finished `sets`, the `current_set` and the
+    /// `sudden_death` leg, which is `null` unless the match went to overtime. Each set has
+    /// the layout described at [Set::to_json]. Use [MatchDocument::from_json] to read it back.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("matches can always be serialized")
+    }
+
+    /// Legs won and lost by the player over all sets of the match, including the sudden death leg
+    pub fn record(&self, player_index: usize) -> (u32, u32) {
+        let (won, lost) = self
+            .sets
+            .iter()
+            .chain(std::iter::once(&self.current_set))
+            .fold((0, 0), |(won, lost), set| {
+                let finished = set.current_leg_number() - 1;
+                let set_won = set.legs_won(player_index);
+
+                (won + set_won as u32, lost + (finished - set_won) as u32)
+            });
+
+        match &self.sudden_death {
+            Some(leg) if !leg.is_retired(player_index) => match leg.winner() {
+                Some(winner) if winner == player_index => (won + 1, lost),
+                Some(_) => (won, lost + 1),
+                None => (won, lost),
+            },
+            _ => (won, lost),
+        }
+    }
+
+    /// Percentage of checkout attempts a player converted across every leg of the match
+    pub fn checkout_rate(&self, player_index: usize) -> f64 {
+        let (attempts, checkouts) = self
+            .sets
+            .iter()
+            .chain(std::iter::once(&self.current_set))
+            .map(|set| set.checkout_tally(player_index))
+            .chain(std::iter::once(set::checkout_tally(
+                &self.sudden_death,
+                player_index,
+            )))
+            .fold(
+                (0, 0),
+                |(attempts, checkouts), (set_attempts, set_checkouts)| {
+                    (attempts + set_attempts, checkouts + set_checkouts)
+                },
+            );
+
+        set::checkout_rate(attempts, checkouts)
+    }
+
+    /// Number of sets won by the player
+    pub fn sets_won(&self, player_index: usize) -> usize {
+        self.sets
+            .iter()
+            .chain(std::iter::once(&self.current_set))
+            .filter(|set| set.set_winner() == Some(player_index))
+            .count()
+    }
+
+    /// Check if the match is level after all sets and decided by a sudden death leg
+    pub fn is_overtime(&self) -> bool {
+        self.sudden_death.is_some()
+    }
+
+    pub fn sudden_death_leg(&self) -> Option<&Leg<'a>> {
+        self.sudden_death.as_ref()
+    }
+
+    /// The player that won the required number of sets or the sudden death leg
+    ///
+    /// With [OvertimeRule::SuddenDeathLeg] a player leading alone after all sets wins as well.
+    pub fn winner(&self) -> Option<usize> {
+        if let Some(leg) = &self.sudden_death {
+            return leg.winner();
+        }
+
+        let sets_to_win = self.ruleset.match_format().sets_to_win() as usize;
+
+        (0..self.participants.count())
+            .find(|&player| self.sets_won(player) >= sets_to_win)
+            .or_else(|| match self.leaders()[..] {
+                [leader] if self.decided_by_sudden_death() => Some(leader),
+                _ => None,
+            })
+    }
+
+    /// The players with the most sets won
+    fn leaders(&self) -> Vec<usize> {
+        let most = (0..self.participants.count())
+            .map(|player| self.sets_won(player))
+            .max()
+            .unwrap_or(0);
+
+        (0..self.participants.count())
+            .filter(|&player| self.sets_won(player) == most)
+            .collect()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Check if all sets of the match format were played and the overtime rule asks for a sudden death leg
+    fn decided_by_sudden_death(&self) -> bool {
+        let played = self.sets.len() + usize::from(self.current_set.set_winner().is_some());
+
+        *self.ruleset.overtime_rule() == OvertimeRule::SuddenDeathLeg
+            && self
+                .ruleset
+                .match_format()
+                .max_sets()
+                .is_some_and(|max_sets| played >= usize::from(max_sets))
+    }
+
+    pub fn add_throw(mut self, throw: Throw) -> Result<Self, LegError> {
+        if self.is_finished() {
+            return Err(LegError::ThrowsAfterFinish(1));
+        }
+
+        if let Some(leg) = self.sudden_death.take() {
+            self.sudden_death = Some(leg.add_throw(throw)?.leg);
+            return Ok(self);
+        }
+
+        self.current_set = self.current_set.add_throw(throw)?;
+
+        Ok(self.start_next_set())
+    }
+
+    /// Retire a participant from the running leg and the rest of the match
+    ///
+    /// See [Set::retire], the last participant left wins the remaining sets.
+    pub fn retire(mut self, player_index: usize) -> Result<Self, LegError> {
+        if let Some(leg) = self.sudden_death.take() {
+            self.sudden_death = Some(leg.retire(player_index)?.leg);
+            return Ok(self);
+        }
+
+        self.current_set = self.current_set.retire(player_index)?;
+
+        Ok(self.start_next_set())
+    }
+
+    /// Continue with the next set or the sudden death leg once the current set is won
+    fn start_next_set(mut self) -> Self {
+        while self.current_set.set_winner().is_some() && !self.is_finished() {
+            self.first_player = (self.first_player + 1) % self.participants.count();
+
+            if self.decided_by_sudden_death() {
+                self.sudden_death = Some(
+                    self.current_set
+                        .current_leg()
+                        .next_leg_between(self.first_player, &self.leaders()),
+                );
+                break;
+            }
+
+            let next_set = self.current_set.next_set(self.first_player);
+            self.sets
+                .push(std::mem::replace(&mut self.current_set, next_set));
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        player::Player,
+        x01::{
+            participants::test_participants,
+            ruleset::{MatchFormat, OutRule, OvertimeRule, Ruleset, SetOptions},
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn record_is_tallied_over_sets() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .match_format(MatchFormat::FirstTo(2))
+            .build();
+
+        let mut game = Match::new(&ruleset, &participants, 0).unwrap();
+
+        // Both players win the legs they start, Anna takes the first set 2-1.
+        // Pete starts the second set and wins it 2-0 by breaking the throw of Anna.
+        let legs = [
+            "T20 D20 1",
+            "T20 D20 1",
+            "T20 D20 1",
+            "T20 D20 1",
+            "0 0 0 T20 D20 1",
+        ];
+
+        for throw in legs.iter().flat_map(|leg| leg.split(' ')) {
+            game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(game.current_set_number(), 3);
+        assert_eq!(game.record(0), (2, 3));
+        assert_eq!(game.record(1), (3, 2));
+    }
+
+    /// Play single leg sets of 101, the winner of each set is given by index
+    fn play_sets<'a>(mut game: Match<'a>, winners: &[usize]) -> Match<'a> {
+        let count = game.participants.count();
+
+        for &winner in winners {
+            // The first player rotates with every set, everyone before the winner misses
+            let first = (game.current_set_number() - 1) % count;
+            let misses = (winner + count - first) % count;
+            let throws = std::iter::repeat_n("0", 3 * misses).chain(["T20", "D20", "1"]);
+
+            for throw in throws {
+                game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+            }
+        }
+
+        game
+    }
+
+    fn ruleset(match_format: MatchFormat) -> Ruleset {
+        Ruleset::new()
+            .score(101)
+            .unwrap()
+            .match_format(match_format)
+            .build()
+    }
+
+    #[test]
+    fn best_of_5_is_won_with_three_sets() {
+        let participants = test_participants(2);
+        let ruleset = ruleset(MatchFormat::BestOf(5));
+
+        let game = play_sets(
+            Match::new(&ruleset, &participants, 0).unwrap(),
+            &[0, 1, 0, 1],
+        );
+        assert_eq!(game.winner(), None);
+
+        let game = play_sets(game, &[0]);
+        assert_eq!(game.winner(), Some(0));
+        assert_eq!(game.sets_won(0), 3);
+        assert_eq!(game.sets_won(1), 2);
+        assert_eq!(
+            game.add_throw(Throw::from_str("T20").unwrap()),
+            Err(LegError::ThrowsAfterFinish(1))
+        );
+    }
+
+    #[test]
+    fn first_to_3_needs_three_sets_regardless_of_sets_played() {
+        let participants = test_participants(2);
+        let first_to_3 = ruleset(MatchFormat::FirstTo(3));
+        let best_of_3 = ruleset(MatchFormat::BestOf(3));
+
+        let sets = [0, 1, 0, 1];
+
+        let first_to = play_sets(Match::new(&first_to_3, &participants, 0).unwrap(), &sets);
+        assert!(!first_to.is_finished());
+        assert_eq!(play_sets(first_to, &[1]).winner(), Some(1));
+
+        let best_of = play_sets(
+            Match::new(&best_of_3, &participants, 0).unwrap(),
+            &sets[..3],
+        );
+        assert_eq!(best_of.winner(), Some(0));
+    }
+
+    #[test]
+    fn level_match_goes_to_sudden_death_leg() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .match_format(MatchFormat::BestOf(2))
+            .overtime_rule(OvertimeRule::SuddenDeathLeg)
+            .build();
+
+        let game = play_sets(Match::new(&ruleset, &participants, 0).unwrap(), &[0, 1]);
+
+        assert!(game.is_overtime());
+        assert_eq!(game.winner(), None);
+        assert_eq!(game.current_set_number(), 2);
+        assert_eq!(game.sudden_death_leg().unwrap().first_player(), 0);
+
+        let mut game = game;
+        for throw in ["T20", "D20", "1"] {
+            game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(game.winner(), Some(0));
+        assert_eq!((game.sets_won(0), game.sets_won(1)), (1, 1));
+        assert_eq!(game.record(0), (2, 1));
+        assert_eq!(game.record(1), (1, 2));
+    }
+
+    fn three_participants() -> Participants {
+        let players: Vec<_> = ["Anna", "Pete", "Tina"]
+            .iter()
+            .map(|name| Player::new(name).unwrap())
+            .collect();
+
+        Participants::new().add_all(&players).build().unwrap()
+    }
+
+    #[test]
+    fn sudden_death_leg_is_played_by_the_tied_leaders_only() {
+        let participants = three_participants();
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .match_format(MatchFormat::BestOf(4))
+            .overtime_rule(OvertimeRule::SuddenDeathLeg)
+            .build();
+
+        let game = play_sets(
+            Match::new(&ruleset, &participants, 0).unwrap(),
+            &[0, 1, 0, 1],
+        );
+
+        let leg = game.sudden_death_leg().unwrap();
+        assert!(!leg.is_retired(0));
+        assert!(!leg.is_retired(1));
+        assert!(leg.is_retired(2));
+
+        let mut game = game;
+        for throw in ["T20", "D20", "1"] {
+            game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(game.winner(), Some(1));
+        assert_eq!(game.record(0), (2, 3));
+        assert_eq!(game.record(2), (0, 4));
+    }
+
+    #[test]
+    fn single_leader_after_all_sets_wins_without_sudden_death() {
+        let participants = three_participants();
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .match_format(MatchFormat::BestOf(4))
+            .overtime_rule(OvertimeRule::SuddenDeathLeg)
+            .build();
+
+        let game = play_sets(
+            Match::new(&ruleset, &participants, 0).unwrap(),
+            &[0, 1, 0, 2],
+        );
+
+        assert!(!game.is_overtime());
+        assert_eq!(game.winner(), Some(0));
+    }
+
+    #[test]
+    fn retiring_hands_the_match_to_the_last_player_left() {
+        let participants = test_participants(2);
+        let ruleset = ruleset(MatchFormat::FirstTo(3));
+
+        let game = play_sets(Match::new(&ruleset, &participants, 0).unwrap(), &[0]);
+        let game = game.add_throw(Throw::triple(20).unwrap()).unwrap();
+
+        let game = game.retire(0).unwrap();
+
+        assert_eq!(game.winner(), Some(1));
+        assert_eq!((game.sets_won(0), game.sets_won(1)), (1, 3));
+        assert_eq!(
+            game.add_throw(Throw::miss().unwrap()),
+            Err(LegError::ThrowsAfterFinish(1))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_restores_match() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .match_format(MatchFormat::BestOf(2))
+            .overtime_rule(OvertimeRule::SuddenDeathLeg)
+            .build();
+
+        let mut game = play_sets(Match::new(&ruleset, &participants, 0).unwrap(), &[0, 1]);
+        for throw in ["T20", "D20", "1"] {
+            game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+        assert_eq!(game.winner(), Some(0));
+
+        let document = MatchDocument::from_json(&game.to_json()).unwrap();
+
+        assert_eq!(document.game_match(), Ok(game));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_match_documents_are_rejected() {
+        let participants = test_participants(2);
+        let ruleset = ruleset(MatchFormat::FirstTo(2));
+        let game = play_sets(Match::new(&ruleset, &participants, 0).unwrap(), &[0]);
+
+        let json: serde_json::Value = serde_json::from_str(&game.to_json()).unwrap();
+        let rebuild = |change: &dyn Fn(&mut serde_json::Value)| {
+            let mut json = json.clone();
+            change(&mut json);
+            MatchDocument::from_json(&json.to_string())
+                .unwrap()
+                .game_match()
+                .map(|_| ())
+        };
+
+        assert_eq!(rebuild(&|_| {}), Ok(()));
+        assert_eq!(
+            rebuild(&|json| json["first_player"] = 2.into()),
+            Err(MatchDocumentError::InvalidFirstPlayer(2))
+        );
+        assert_eq!(
+            rebuild(&|json| json["sets"][0]["ruleset"]["score"] = 301.into()),
+            Err(MatchDocumentError::MismatchedSet(0))
+        );
+        assert_eq!(
+            rebuild(&|json| json["sets"][0] = json["current_set"].clone()),
+            Err(MatchDocumentError::UnfinishedSet(0))
+        );
+    }
+
+    #[test]
+    fn checkout_rate_is_tallied_over_all_legs() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .match_format(MatchFormat::FirstTo(2))
+            .build();
+
+        let mut game = Match::new(&ruleset, &participants, 0).unwrap();
+        assert_eq!(game.checkout_rate(0), 0.0);
+
+        // Anna misses one dart at 40 in the first leg, then one more before hitting D20 in the second
+        let legs = ["T20 1 0 T20 1 D20", "0 0 0 T20 1 0 0 0 0 D20"];
+
+        for throw in legs.iter().flat_map(|leg| leg.split(' ')) {
+            game = game.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(game.current_set_number(), 3);
+        assert!((game.checkout_rate(0) - 100.0 / 3.0).abs() < 1e-9);
+        assert_eq!(game.checkout_rate(1), 100.0);
+    }
+
+    #[test]
+    fn level_match_without_overtime_plays_another_set() {
+        let participants = test_participants(2);
+        let ruleset = ruleset(MatchFormat::BestOf(2));
+
+        let game = play_sets(Match::new(&ruleset, &participants, 0).unwrap(), &[0, 1]);
+
+        assert!(!game.is_overtime());
+        assert_eq!(game.current_set_number(), 3);
+        assert_eq!(play_sets(game, &[0]).winner(), Some(0));
+    }
+}
@@ -1,200 +1,2799 @@
-use crate::{player::Player, throw::Throw, turn::Turn};
+use std::{collections::HashMap, error::Error, fmt::Display, time::Duration};
 
-use super::{participants::Participants, ruleset::Ruleset};
+use crate::{
+    clock::Clock,
+    player::{NewPlayerError, Player},
+    throw::{InvalidThrowError, Throw},
+    turn::{ThrowError, Turn, TurnCategory, MAX_THROWS},
+};
+
+use super::{
+    participants::{Participants, RetireError},
+    ruleset::{is_valid_score, BustRule, InRule, InvalidScoreError, OutRule, Ruleset},
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct CurrentPlayer {
     index: usize,
     points: u32,
     turn: Turn,
+    started: Option<Duration>,
+    /// The throw the player called to finish the leg with
+    nomination: Option<Throw>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum State {
+    /// The leg was won by `winner` who threw `darts` darts in it
+    Finished {
+        winner: usize,
+        darts: usize,
+    },
+    Unfinished,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThrowResult<'a> {
+    pub state: State,
+    pub leg: Leg<'a>,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            State::Finished { .. } => write!(f, "Finished"),
+            State::Unfinished => write!(f, "Unfinished"),
+        }
+    }
+}
+
+impl Display for ThrowResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.state {
+            State::Finished { .. } => write!(f, "{}!", self.state),
+            State::Unfinished => write!(
+                f,
+                "{}: {} on {}",
+                self.state,
+                self.leg.current_player().name(),
+                self.leg.current_points()
+            ),
+        }
+    }
+}
+
+impl ThrowResult<'_> {
+    fn unfinished(leg: Leg) -> ThrowResult {
+        ThrowResult {
+            state: State::Unfinished,
+            leg,
+        }
+    }
+    fn finished(leg: Leg, winner: usize) -> ThrowResult {
+        ThrowResult {
+            state: State::Finished {
+                winner,
+                darts: leg.darts_thrown(winner),
+            },
+            leg,
+        }
+    }
+}
+
+/// Overview of a finished leg, see [Leg::summary]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegSummary {
+    /// The player who threw the finishing dart
+    pub winner: Player,
+    pub darts_thrown_by_winner: usize,
+    pub three_dart_average_by_winner: f64,
+    /// The most points scored in a turn by any participant
+    pub highest_turn: u8,
+    /// The number of turns of all participants
+    pub total_turns: usize,
+    pub checkout_throw: Throw,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LegError {
+    /// The leg was finished but the given number of throws were left over
+    ThrowsAfterFinish(usize),
+    /// The recorded turns of the player sum up to more than the start score
+    InvalidScore(usize),
+    /// The current turn does not accept any more throws
+    InvalidTurn(ThrowError),
+    /// The current turn already has all its throws but was not committed
+    TurnComplete,
+    /// More throws were given than darts are left in the current turn
+    TooManyThrows(usize),
+    /// The participant cannot retire from the leg
+    InvalidRetirement(RetireError),
+}
+
+impl Error for LegError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for LegError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LegError::ThrowsAfterFinish(n) => {
+                writeln!(f, "Leg finished with {} throws left over", n)
+            }
+            LegError::InvalidScore(player) => {
+                writeln!(f, "Turns of player {} exceed the start score", player)
+            }
+            LegError::InvalidTurn(err) => write!(f, "{}", err),
+            LegError::TurnComplete => writeln!(f, "The current turn already has all throws"),
+            LegError::TooManyThrows(n) => writeln!(f, "{} throws do not fit into the turn", n),
+            LegError::InvalidRetirement(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// The byte marking a forfeited turn in [Leg::to_bytes], no throw is packed into it
+pub const FORFEIT_BYTE: u8 = 0b0011_1110;
+
+/// An error that occurs when unpacking a leg with [Leg::from_bytes]
+#[derive(Debug, PartialEq, Eq)]
+pub enum UnpackLegError {
+    InvalidThrow(InvalidThrowError),
+    InvalidLeg(LegError),
+}
+
+impl Error for UnpackLegError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for UnpackLegError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnpackLegError::InvalidThrow(err) => write!(f, "{}", err),
+            UnpackLegError::InvalidLeg(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// The leg was finished before all throws given to [Leg::add_throws_until_turn_end] were added
+#[derive(Debug, PartialEq, Eq)]
+pub struct EarlyFinish<'a> {
+    /// The throws after the finishing throw
+    pub remaining_throws: Vec<Throw>,
+    pub result: ThrowResult<'a>,
+}
+
+/// An error that occurs when adding throws with [Leg::add_throws_until_turn_end]
+#[derive(Debug, PartialEq, Eq)]
+pub enum AddThrowsError<'a> {
+    EarlyFinish(EarlyFinish<'a>),
+    InvalidThrow(LegError),
+}
+
+impl Error for AddThrowsError<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for AddThrowsError<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddThrowsError::EarlyFinish(finish) => writeln!(
+                f,
+                "Leg finished with {} throws left over",
+                finish.remaining_throws.len()
+            ),
+            AddThrowsError::InvalidThrow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CreateLegError {
+    InvalidFirstPlayer(usize),
+}
+
+impl Error for CreateLegError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for CreateLegError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateLegError::InvalidFirstPlayer(n) => {
+                writeln!(f, "First player '{}' is invalid.", n)
+            }
+        }
+    }
+}
+
+/// An error that occurs when restoring a leg with [Leg::restore]
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestoreLegError {
+    /// The checkpoint holds turns for a different number of participants
+    WrongParticipantCount(usize),
+    /// The current or first player is not a participant
+    InvalidPlayer(usize),
+    /// A turn of the participant holds more than [MAX_THROWS] throws
+    InvalidTurn(usize),
+    /// The turns of the participant do not match their score
+    InvalidScore(usize),
+}
+
+impl Error for RestoreLegError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for RestoreLegError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreLegError::WrongParticipantCount(n) => {
+                writeln!(f, "Checkpoint holds {} participants", n)
+            }
+            RestoreLegError::InvalidPlayer(n) => writeln!(f, "Player '{}' is invalid.", n),
+            RestoreLegError::InvalidTurn(n) => {
+                writeln!(f, "A turn of player {} has too many throws", n)
+            }
+            RestoreLegError::InvalidScore(n) => {
+                writeln!(f, "Turns of player {} do not match the score", n)
+            }
+        }
+    }
+}
+
+/// What happens to the current turn after a throw
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Continue,
+    TurnOver,
+    Bust,
+    Finished,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ParticipantData {
+    turns: Vec<Turn>,
+    /// How long each turn took, only recorded if the leg has a clock
+    durations: Vec<Duration>,
+    /// Retired participants keep their turns but do not throw anymore
+    #[cfg_attr(feature = "serde", serde(default))]
+    retired: bool,
+}
+
+/// The clock of a leg, clocks are ignored when comparing legs
+#[derive(Clone, Copy, Debug, Default)]
+struct LegClock<'a>(Option<&'a dyn Clock>);
+
+impl LegClock<'_> {
+    fn now(&self) -> Option<Duration> {
+        self.0.map(|clock| clock.now())
+    }
+}
+
+impl PartialEq for LegClock<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LegClock<'_> {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Leg<'a> {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ruleset: &'a Ruleset,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    participants: &'a Participants,
+    current: CurrentPlayer,
+    data: Vec<ParticipantData>,
+    first_player: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clock: LegClock<'a>,
+}
+
+/// Owned state of a [Leg] without the ruleset and participants it refers to
+///
+/// Created with [Leg::checkpoint], turned back into a leg with [Leg::restore].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LegCheckpoint {
+    current: CurrentPlayer,
+    data: Vec<ParticipantData>,
+    first_player: usize,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum State {
-    Finished,
-    Unfinished,
-}
+impl<'a> Leg<'a> {
+    fn calculate_score(&self, player_index: usize, start_score: u32) -> Option<u32> {
+        let turns = &self.data[player_index].turns;
+
+        // Resetting to the start discards every turn up to the last bust
+        let first_counted = match self.ruleset.bust_rule() {
+            BustRule::ResetTurn | BustRule::NoBust => 0,
+            BustRule::ResetToStart => turns
+                .iter()
+                .rposition(|turn| turn.is_bust())
+                .map_or(0, |index| index + 1),
+        };
+
+        let sum = turns[first_counted..]
+            .iter()
+            .filter_map(|turn| {
+                if turn.is_bust() {
+                    None
+                } else {
+                    Some(turn.points() as u32)
+                }
+            })
+            .sum();
+
+        start_score.checked_sub(sum)
+    }
+
+    /// Create a leg started by `first_player`
+    ///
+    /// The index is not checked, use [Leg::new_with_first_player] for untrusted input.
+    pub fn new(ruleset: &'a Ruleset, participants: &'a Participants, first_player: usize) -> Self {
+        let data = participants
+            .participants
+            .iter()
+            .map(|participant| ParticipantData {
+                retired: participant.retired,
+                ..Default::default()
+            })
+            .collect();
+
+        // Nobody has thrown yet, so the first turn starts at the full score
+        let mut leg = Self {
+            ruleset,
+            participants,
+            current: CurrentPlayer {
+                points: *ruleset.score(),
+                ..Default::default()
+            },
+            data,
+            first_player,
+            clock: LegClock::default(),
+        };
+        leg.current.index = leg.next_active(first_player).unwrap_or(first_player);
+
+        leg
+    }
+
+    /// Create the next leg started by `first_player`, participants that retired stay retired
+    pub(crate) fn next_leg(&self, first_player: usize) -> Leg<'a> {
+        let mut leg = Leg::new(self.ruleset, self.participants, first_player);
+
+        for (next, data) in leg.data.iter_mut().zip(&self.data) {
+            next.retired = data.retired;
+        }
+        leg.current.index = leg.next_active(first_player).unwrap_or(first_player);
+        leg.clock = self.clock;
+        leg.current.started = leg.clock.now();
+
+        leg
+    }
+
+    /// Create the next leg between the given participants only, the others sit it out as if retired
+    pub(crate) fn next_leg_between(&self, first_player: usize, players: &[usize]) -> Leg<'a> {
+        let mut leg = self.next_leg(first_player);
+
+        for (index, data) in leg.data.iter_mut().enumerate() {
+            data.retired |= !players.contains(&index);
+        }
+        leg.current.index = leg.next_active(first_player).unwrap_or(first_player);
+
+        leg
+    }
+
+    /// Create a leg started by `first_player`, failing if there is no such participant
+    pub fn new_with_first_player(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        first_player: usize,
+    ) -> Result<Self, CreateLegError> {
+        if first_player >= participants.count() {
+            Err(CreateLegError::InvalidFirstPlayer(first_player))
+        } else {
+            Ok(Self::new(ruleset, participants, first_player))
+        }
+    }
+
+    /// Rebuild a leg from a checkpoint and the ruleset and participants it was played with
+    ///
+    /// Fails if the checkpoint does not fit the participants or its turns are invalid.
+    /// Checkpoints hold no clock, use [Leg::with_clock] to time the restored leg.
+    pub fn restore(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        checkpoint: LegCheckpoint,
+    ) -> Result<Self, RestoreLegError> {
+        let count = participants.count();
+
+        if checkpoint.data.len() != count {
+            return Err(RestoreLegError::WrongParticipantCount(
+                checkpoint.data.len(),
+            ));
+        }
+
+        for index in [checkpoint.current.index, checkpoint.first_player] {
+            if index >= count {
+                return Err(RestoreLegError::InvalidPlayer(index));
+            }
+        }
+
+        let leg = Leg {
+            ruleset,
+            participants,
+            current: checkpoint.current,
+            data: checkpoint.data,
+            first_player: checkpoint.first_player,
+            clock: LegClock::default(),
+        };
+
+        for (index, data) in leg.data.iter().enumerate() {
+            let current_turn = (index == leg.current.index).then_some(&leg.current.turn);
+
+            if data
+                .turns
+                .iter()
+                .chain(current_turn)
+                .any(|turn| turn.num_throws() > MAX_THROWS)
+            {
+                return Err(RestoreLegError::InvalidTurn(index));
+            }
+
+            if leg.remaining_for(index).is_none() {
+                return Err(RestoreLegError::InvalidScore(index));
+            }
+        }
+
+        if leg.remaining_for(leg.current.index) != Some(leg.current.points) {
+            return Err(RestoreLegError::InvalidScore(leg.current.index));
+        }
+
+        Ok(leg)
+    }
+
+    /// Capture the state of the leg, e.g. to undo throws later on
+    pub fn checkpoint(&self) -> LegCheckpoint {
+        LegCheckpoint {
+            current: self.current.clone(),
+            data: self.data.clone(),
+            first_player: self.first_player,
+        }
+    }
+
+    /// Measure the duration of turns with the given clock, starting with the current turn
+    pub fn with_clock(mut self, clock: &'a dyn Clock) -> Self {
+        self.clock = LegClock(Some(clock));
+        self.current.started = self.clock.now();
+        self
+    }
+
+    /// Create a leg and replay the given throws into it
+    ///
+    /// Fails if the leg is finished before all throws are used.
+    pub fn from_throws(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        first_player: usize,
+        throws: &[Throw],
+    ) -> Result<Self, LegError> {
+        let mut leg = Leg::new(ruleset, participants, first_player);
+
+        for (index, throw) in throws.iter().enumerate() {
+            let ThrowResult { state, leg: next } = leg.add_throw(throw.clone())?;
+            leg = next;
+
+            if state != State::Unfinished && index + 1 < throws.len() {
+                return Err(LegError::ThrowsAfterFinish(throws.len() - index - 1));
+            }
+        }
+
+        Ok(leg)
+    }
+
+    /// Pack the throws of the leg into one byte per throw, see [Throw::to_byte]
+    ///
+    /// A forfeited turn is followed by [FORFEIT_BYTE].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        for (_, turn) in self.rounds().into_iter().flatten() {
+            bytes.extend(turn.throws().iter().map(Throw::to_byte));
+
+            if turn.is_forfeited() {
+                bytes.push(FORFEIT_BYTE);
+            }
+        }
+
+        bytes
+    }
+
+    /// Create a leg from throws packed with [Leg::to_bytes]
+    pub fn from_bytes(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        first_player: usize,
+        bytes: &[u8],
+    ) -> Result<Self, UnpackLegError> {
+        let mut leg = Leg::new(ruleset, participants, first_player);
+
+        for (index, byte) in bytes.iter().enumerate() {
+            let result = if *byte == FORFEIT_BYTE {
+                leg.forfeit_turn()
+            } else {
+                leg.add_throw(Throw::from_byte(*byte).map_err(UnpackLegError::InvalidThrow)?)
+            };
+            let ThrowResult { state, leg: next } = result.map_err(UnpackLegError::InvalidLeg)?;
+            leg = next;
+
+            if state != State::Unfinished && index + 1 < bytes.len() {
+                return Err(UnpackLegError::InvalidLeg(LegError::ThrowsAfterFinish(
+                    bytes.len() - index - 1,
+                )));
+            }
+        }
+
+        Ok(leg)
+    }
+
+    fn begin_turn(self, next_player: usize) -> Result<Self, LegError> {
+        let points = self.calculate_score(next_player, *self.ruleset.score());
+
+        if let Some(points) = points {
+            Ok(Leg {
+                current: CurrentPlayer {
+                    index: next_player,
+                    points,
+                    turn: Turn::new(),
+                    started: self.clock.now(),
+                    nomination: None,
+                },
+                ..self
+            })
+        } else {
+            Err(LegError::InvalidScore(next_player))
+        }
+    }
+
+    fn bust_turn(mut self) -> Result<ThrowResult<'a>, LegError> {
+        self.current.turn.bust();
+        self.next_turn()
+    }
+
+    /// End the turn of the current player without scoring and continue with the next player
+    ///
+    /// Unlike throwing misses, no further darts are recorded for the turn.
+    pub fn forfeit_turn(mut self) -> Result<ThrowResult<'a>, LegError> {
+        self.current.turn.forfeit();
+        self.next_turn()
+    }
+
+    fn record_turn_duration(&mut self) {
+        if let (Some(started), Some(now)) = (self.current.started, self.clock.now()) {
+            self.data[self.current.index]
+                .durations
+                .push(now.saturating_sub(started));
+        }
+    }
+
+    fn next_turn(mut self) -> Result<ThrowResult<'a>, LegError> {
+        self.record_turn_duration();
+        let turn = std::mem::take(&mut self.current.turn);
+        self.data[self.current.index].turns.push(turn);
+        let next_player = (self.current.index + 1) % self.data.len();
+        let next_player = self.next_active(next_player).unwrap_or(next_player);
+        Ok(ThrowResult::unfinished(self.begin_turn(next_player)?))
+    }
+
+    /// Retire a participant from the leg, e.g. because they have to leave
+    ///
+    /// The turn in progress of a retiring current player is forfeited. Once a single
+    /// participant is left, they win the leg.
+    pub fn retire(mut self, player_index: usize) -> Result<ThrowResult<'a>, LegError> {
+        let retire_error = match self.data.get(player_index) {
+            None => Some(RetireError::IndexOutOfRange(player_index)),
+            Some(data) if data.retired => Some(RetireError::AlreadyRetired(player_index)),
+            Some(_) if self.active_count() == 1 => Some(RetireError::LastActive(player_index)),
+            Some(_) => None,
+        };
+
+        if let Some(err) = retire_error {
+            return Err(LegError::InvalidRetirement(err));
+        }
+
+        self.data[player_index].retired = true;
+
+        let mut result = if player_index == self.current.index {
+            self.forfeit_turn()?
+        } else {
+            ThrowResult::unfinished(self)
+        };
+
+        if let Some(winner) = result.leg.winner() {
+            result = ThrowResult::finished(result.leg, winner);
+        }
+
+        Ok(result)
+    }
+
+    /// Check if the participant retired from the leg
+    pub fn is_retired(&self, player_index: usize) -> bool {
+        self.data.get(player_index).is_some_and(|data| data.retired)
+    }
+
+    /// Number of participants that did not retire from the leg
+    pub fn active_count(&self) -> usize {
+        self.data.iter().filter(|data| !data.retired).count()
+    }
+
+    /// The first participant that did not retire, starting at `index` and wrapping around
+    fn next_active(&self, index: usize) -> Option<usize> {
+        let count = self.data.len();
+
+        (0..count)
+            .map(|offset| (index + offset) % count)
+            .find(|&index| !self.data[index].retired)
+    }
+
+    pub fn current_player(&self) -> &Player {
+        let index = self.current.index;
+
+        self.participants[index].thrower(self.data[index].turns.len())
+    }
+
+    /// Index of the participant currently throwing
+    pub fn current_player_index(&self) -> usize {
+        self.current.index
+    }
+
+    /// Call the throw the current player wants to finish the leg with.
+    ///
+    /// Until the turn is over, finishing with any other throw is a bust.
+    pub fn nominate(&mut self, target: Throw) {
+        self.current.nomination = Some(target);
+    }
+
+    pub fn current_turn(&self) -> &Turn {
+        &self.current.turn
+    }
+
+    pub fn current_points(&self) -> u32 {
+        // A turn scoring more than the remaining points is a bust and scores nothing
+        self.current
+            .points
+            .saturating_sub(self.current.turn.points().into())
+    }
+
+    /// Remaining points of a participant after their committed turns, ignoring the turn in progress
+    ///
+    /// `None` if there is no participant with the index.
+    pub fn remaining_for(&self, player_index: usize) -> Option<u32> {
+        if player_index >= self.data.len() {
+            return None;
+        }
+
+        self.calculate_score(player_index, *self.ruleset.score())
+    }
+
+    /// Remaining points of a participant, including the turn in progress
+    fn remaining_points(&self, player_index: usize) -> u32 {
+        if player_index == self.current.index {
+            self.current_points()
+        } else {
+            self.remaining_for(player_index).unwrap_or_default()
+        }
+    }
+
+    /// Call `f` with every entry of [Leg::all_player_scores], e.g. to render a scoreboard
+    pub fn for_each_player<F: FnMut(&Player, u32)>(&self, mut f: F) {
+        for (player, points) in self.all_player_scores() {
+            f(player, points);
+        }
+    }
+
+    /// Every participant with their remaining points, including the turn in progress
+    ///
+    /// Teams are listed as their first member.
+    pub fn all_player_scores(&self) -> Vec<(&Player, u32)> {
+        self.participants
+            .participants
+            .iter()
+            .enumerate()
+            .map(|(index, participant)| (&participant.player, self.remaining_points(index)))
+            .collect()
+    }
+
+    /// Participant indices ordered by their remaining points, the leader first
+    ///
+    /// Only committed turns count, participants on the same score keep their order.
+    pub fn rankings(&self) -> Vec<usize> {
+        let mut rankings: Vec<usize> = (0..self.data.len()).collect();
+        rankings.sort_by_key(|&index| self.remaining_for(index).unwrap_or_default());
+
+        rankings
+    }
+
+    /// Every participant with their remaining points after their committed turns
+    ///
+    /// Unlike [Leg::all_player_scores] the turn in progress is not taken into account.
+    pub fn standings(&self) -> Vec<(&Player, u32)> {
+        self.participants
+            .participants
+            .iter()
+            .enumerate()
+            .map(|(index, participant)| {
+                (
+                    &participant.player,
+                    self.remaining_for(index).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    /// All turns of a player including the one currently in progress if it has throws
+    fn turns(&self, player_index: usize) -> impl Iterator<Item = &Turn> {
+        let current = if player_index == self.current.index && self.current.turn.num_throws() > 0 {
+            Some(&self.current.turn)
+        } else {
+            None
+        };
+
+        self.data[player_index].turns.iter().chain(current)
+    }
+
+    /// The turns of all players grouped by round in the order they were thrown
+    pub(crate) fn rounds(&self) -> Vec<Vec<(usize, &Turn)>> {
+        let count = self.participants.count();
+        let mut rounds = vec![];
+
+        for round in 0.. {
+            let round_turns: Vec<_> = (0..count)
+                .map(|offset| (self.first_player + offset) % count)
+                .filter_map(|index| self.turns(index).nth(round).map(|turn| (index, turn)))
+                .collect();
+
+            if round_turns.is_empty() {
+                break;
+            }
+
+            rounds.push(round_turns);
+        }
+
+        rounds
+    }
+
+    /// Every throw of the leg with the index of its participant, in the order they were thrown
+    pub fn all_throws(&self) -> impl Iterator<Item = (usize, &Throw)> {
+        self.rounds()
+            .into_iter()
+            .flatten()
+            .flat_map(|(index, turn)| turn.throws().iter().map(move |throw| (index, throw)))
+    }
+
+    pub fn ruleset(&self) -> &Ruleset {
+        self.ruleset
+    }
+
+    pub fn participants(&self) -> &Participants {
+        self.participants
+    }
+
+    pub fn first_player(&self) -> usize {
+        self.first_player
+    }
+
+    /// The number of the round in progress, a round is over once every player had a turn
+    ///
+    /// Retired participants do not throw anymore, so they are not waited for.
+    pub fn current_round(&self) -> usize {
+        self.data
+            .iter()
+            .filter(|data| !data.retired)
+            .map(|data| data.turns.len())
+            .min()
+            .unwrap_or(0)
+            + 1
+    }
+
+    /// Number of participants in the leg, including retired ones
+    pub fn player_count(&self) -> usize {
+        self.participants.count()
+    }
+
+    /// A practice leg with a single participant, the turn never passes to anyone else
+    pub fn is_single_player(&self) -> bool {
+        self.player_count() == 1
+    }
+
+    pub fn is_multiplayer(&self) -> bool {
+        self.player_count() > 1
+    }
+
+    /// Export the leg in a PGN like text notation that can be read by [Notation::parse]
+    ///
+    /// [Notation::parse]: super::notation::Notation::parse
+    pub fn as_pgn_like_notation(&self) -> String {
+        super::notation::write(self)
+    }
+
+    /// The index of the player who checked out, if any
+    fn checked_out(&self) -> Option<usize> {
+        if self.current_points() == 0 {
+            Some(self.current.index)
+        } else {
+            None
+        }
+    }
+
+    /// The index of the player who won the leg, if the leg is finished
+    ///
+    /// A leg is also won by the last active player if all others retired.
+    pub fn winner(&self) -> Option<usize> {
+        if self.player_count() > 1 && self.active_count() == 1 {
+            self.next_active(0)
+        } else {
+            self.checked_out()
+        }
+    }
+
+    /// Summarize the leg once a player checked out
+    ///
+    /// Returns None while the leg is running or if it was only won because others retired.
+    pub fn summary(&self) -> Option<LegSummary> {
+        let winner = self.checked_out()?;
+
+        let turns = || (0..self.participants.count()).flat_map(|player| self.turns(player));
+
+        Some(LegSummary {
+            winner: self.current_player().clone(),
+            darts_thrown_by_winner: self.darts_thrown(winner),
+            three_dart_average_by_winner: self.three_dart_average(winner),
+            highest_turn: turns().map(|turn| turn.points()).max().unwrap_or_default(),
+            total_turns: turns().count(),
+            checkout_throw: self.current.turn.throws().last()?.clone(),
+        })
+    }
+
+    /// The participant with the lowest committed remaining score and the margin to the next one
+    ///
+    /// The turn in progress and retired participants are ignored. Returns None on a tie or
+    /// if there is only one participant left.
+    pub fn leader(&self) -> Option<(usize, u32)> {
+        let mut remaining: Vec<_> = (0..self.participants.count())
+            .filter(|&index| !self.is_retired(index))
+            .filter_map(|index| {
+                self.calculate_score(index, *self.ruleset.score())
+                    .map(|points| (points, index))
+            })
+            .collect();
+        remaining.sort();
+
+        match remaining[..] {
+            [(first, index), (second, _), ..] if first < second => Some((index, second - first)),
+            _ => None,
+        }
+    }
+
+    /// Number of darts a player threw in the leg, including the turn in progress
+    pub fn darts_thrown(&self, player_index: usize) -> usize {
+        self.turns(player_index).map(|turn| turn.num_throws()).sum()
+    }
+
+    /// Average points a player scored per dart
+    ///
+    /// Darts of busted or forfeited turns are counted but score nothing. Returns 0.0 if
+    /// the player has not thrown yet.
+    pub fn points_per_dart(&self, player_index: usize) -> f64 {
+        let darts = self.darts_thrown(player_index);
+
+        if darts == 0 {
+            0.0
+        } else {
+            f64::from(self.points_scored(player_index)) / darts as f64
+        }
+    }
+
+    /// Points a player scored in the leg, including the turn in progress
+    pub fn points_scored(&self, player_index: usize) -> u32 {
+        self.turns(player_index)
+            .map(|turn| u32::from(turn.points()))
+            .sum()
+    }
+
+    /// Number of 180s a player threw in the leg
+    pub fn maximums(&self, player_index: usize) -> usize {
+        self.turns(player_index)
+            .filter(|turn| turn.category() == TurnCategory::Maximum)
+            .count()
+    }
+
+    /// Average points a player scored per three darts, see [Leg::points_per_dart]
+    pub fn three_dart_average(&self, player_index: usize) -> f64 {
+        self.points_per_dart(player_index) * 3.0
+    }
+
+    /// The remaining points of the player before each of their darts and after their last dart
+    fn remaining_per_dart(&self, player_index: usize) -> (Vec<(u32, &Throw)>, u32) {
+        let mut remaining = *self.ruleset.score();
+        let mut darts = vec![];
+
+        for turn in self.turns(player_index) {
+            let turn_start = remaining;
+
+            for (index, throw) in turn.throws().iter().enumerate() {
+                darts.push((remaining, throw));
+
+                if !turn.is_ignored(index) {
+                    remaining = remaining.saturating_sub(throw.points().into());
+                }
+            }
+
+            if turn.is_bust() && *self.ruleset.bust_rule() == BustRule::ResetToStart {
+                remaining = *self.ruleset.score();
+            } else if turn.is_bust() || turn.is_forfeited() {
+                remaining = turn_start;
+            }
+        }
+
+        (darts, remaining)
+    }
+
+    /// Number of darts a player threw while the remaining points could be finished with a single dart
+    pub fn checkout_attempts(&self, player_index: usize) -> usize {
+        let out_rule = self.ruleset.out_rule();
+
+        self.remaining_per_dart(player_index)
+            .0
+            .iter()
+            .filter(|(remaining, _)| out_rule.one_dart_finish(*remaining))
+            .count()
+    }
+
+    /// Number of darts a player threw before the remaining points could first be finished
+    /// with a single dart, `None` if the player never got there
+    pub fn throws_until_checkout_was_possible(&self, player_index: usize) -> Option<usize> {
+        let out_rule = self.ruleset.out_rule();
+        let (darts, remaining) = self.remaining_per_dart(player_index);
+
+        darts
+            .iter()
+            .position(|(remaining, _)| out_rule.one_dart_finish(*remaining))
+            .or_else(|| out_rule.one_dart_finish(remaining).then_some(darts.len()))
+    }
+
+    /// How often the player hit each throw, including the darts of busted turns
+    pub fn hit_distribution(&self, player_index: usize) -> HashMap<Throw, usize> {
+        let mut hits = HashMap::new();
+
+        for throw in self.turns(player_index).flat_map(|turn| turn.throws()) {
+            *hits.entry(throw.clone()).or_insert(0) += 1;
+        }
+
+        hits
+    }
+
+    /// Number of darts a player missed while standing on a one dart double out finish
+    ///
+    /// The finishing dart itself is not counted.
+    pub fn darts_at_double(&self, player_index: usize) -> usize {
+        self.remaining_per_dart(player_index)
+            .0
+            .iter()
+            .filter(|(remaining, throw)| {
+                OutRule::Double.one_dart_finish(*remaining)
+                    && !throw.is_achievable_with_out_rule(*remaining, &OutRule::Double)
+            })
+            .count()
+    }
+
+    /// Average time a player took for a turn, if the leg has a clock and the player finished a turn
+    pub fn average_turn_duration(&self, player_index: usize) -> Option<Duration> {
+        let durations = &self.data[player_index].durations;
+
+        if durations.is_empty() {
+            None
+        } else {
+            Some(durations.iter().sum::<Duration>() / durations.len() as u32)
+        }
+    }
+
+    /// Number of successful checkouts of a player in this leg
+    pub fn checkouts(&self, player_index: usize) -> usize {
+        usize::from(self.checked_out() == Some(player_index))
+    }
+
+    /// The points a player finished the leg with, if they checked out
+    pub fn checkout(&self, player_index: usize) -> Option<u32> {
+        if self.checked_out() == Some(player_index) {
+            Some(self.current.points)
+        } else {
+            None
+        }
+    }
+
+    /// Darts the player used in the turn they checked out with, `None` if they did not check out
+    pub fn checkout_darts(&self, player_index: usize) -> Option<u8> {
+        if self.checked_out() == Some(player_index) {
+            Some(self.current.turn.num_throws() as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Decide how the current turn continues after `throw` has been added to it
+    fn add_throw_logic(&self, first_throw: bool, turn: &Turn, throw: &Throw) -> Outcome {
+        if first_throw && !self.ruleset.in_rule().valid_throw(throw) {
+            return Outcome::Bust;
+        }
+
+        // The points left before the throw, the earlier throws of the turn did not bust it
+        let remaining = self
+            .current
+            .points
+            .saturating_sub((turn.points() - throw.points()).into());
+
+        if throw.is_achievable_with_out_rule(remaining, self.ruleset.out_rule()) {
+            let nominated = self
+                .current
+                .nomination
+                .as_ref()
+                .is_none_or(|nomination| nomination == throw);
+
+            return if nominated {
+                Outcome::Finished
+            } else {
+                Outcome::Bust
+            };
+        }
+
+        match remaining.checked_sub(throw.points().into()) {
+            // Player has thrown more points than remain or reached 0 without a valid finisher
+            None | Some(0) => Outcome::Bust,
+            Some(points) if !self.ruleset.out_rule().can_leave(points) => Outcome::Bust,
+            Some(_) if turn.num_throws() == MAX_THROWS => Outcome::TurnOver,
+            Some(_) => Outcome::Continue,
+        }
+    }
+
+    /// Check if the next throw is the first of the current player in this leg
+    ///
+    /// The in rule of the ruleset applies to this throw, e.g. it has to be a double for double in.
+    /// With [BustRule::NoBust] it keeps applying until the player scored.
+    pub fn is_first_turn_of_leg(&self) -> bool {
+        self.data[self.current.index].turns.is_empty() && self.current.turn.num_throws() == 0
+    }
+
+    /// Check if the in rule applies to the next throw
+    ///
+    /// Without busts darts that miss the in rule are ignored, so it applies until a dart scored.
+    fn needs_in_throw(&self) -> bool {
+        self.is_first_turn_of_leg()
+            || (*self.ruleset.bust_rule() == BustRule::NoBust
+                && self.current_points() == *self.ruleset.score())
+    }
+
+    /// Check if adding the throw would bust the current turn, without adding it
+    ///
+    /// A throw into a turn that does not accept any more throws is reported as a bust as well.
+    pub fn would_bust(&self, throw: &Throw) -> bool {
+        let mut turn = self.current.turn.clone();
+
+        turn.add_throw(throw.clone()).is_err()
+            || self.add_throw_logic(self.needs_in_throw(), &turn, throw) == Outcome::Bust
+    }
+
+    pub fn add_throw(mut self, throw: Throw) -> Result<ThrowResult<'a>, LegError> {
+        // Check if current throw results in new turn, win, continue turn, bust of turn
+
+        if self.current.turn.num_throws() >= MAX_THROWS {
+            return Err(LegError::TurnComplete);
+        }
+
+        // Without busts a dart that would bust is kept in the turn but scores nothing
+        let ignored = *self.ruleset.bust_rule() == BustRule::NoBust && self.would_bust(&throw);
+
+        let first_throw = self.needs_in_throw();
+        let turn = &mut self.current.turn;
+        if ignored {
+            turn.add_ignored_throw(throw.clone())
+        } else {
+            turn.add_throw(throw.clone())
+        }
+        .map_err(LegError::InvalidTurn)?;
+
+        let outcome = if !ignored {
+            self.add_throw_logic(first_throw, &self.current.turn, &throw)
+        } else if self.current.turn.num_throws() == MAX_THROWS {
+            Outcome::TurnOver
+        } else {
+            Outcome::Continue
+        };
+
+        match outcome {
+            Outcome::Continue => Ok(ThrowResult::unfinished(self)),
+            Outcome::TurnOver => self.next_turn(),
+            Outcome::Bust => self.bust_turn(),
+            Outcome::Finished => {
+                self.record_turn_duration();
+                let winner = self.current.index;
+                Ok(ThrowResult::finished(self, winner))
+            }
+        }
+    }
+
+    /// Add several throws to the current turn at once
+    ///
+    /// The throws must fit into the darts left in the turn. Throws after the turn
+    /// ended early, i.e. by a bust or a finish, are ignored.
+    pub fn add_turn(self, throws: &[Throw]) -> Result<ThrowResult<'a>, LegError> {
+        let darts_left = MAX_THROWS - self.current.turn.num_throws();
+        if throws.len() > darts_left {
+            return Err(LegError::TooManyThrows(throws.len()));
+        }
+
+        match self.add_throws_until_turn_end(throws.iter().cloned()) {
+            Ok(result) => Ok(result),
+            Err(AddThrowsError::EarlyFinish(finish)) => Ok(finish.result),
+            Err(AddThrowsError::InvalidThrow(err)) => Err(err),
+        }
+    }
+
+    /// Add throws until the current turn ends or the leg is finished
+    ///
+    /// Throws left over after the turn ended are ignored. Throws left over after the
+    /// leg was finished are returned in [AddThrowsError::EarlyFinish].
+    #[allow(clippy::result_large_err)]
+    pub fn add_throws_until_turn_end(
+        self,
+        throws: impl IntoIterator<Item = Throw>,
+    ) -> Result<ThrowResult<'a>, AddThrowsError<'a>> {
+        let mut throws = throws.into_iter();
+        let mut result = ThrowResult::unfinished(self);
+
+        for throw in throws.by_ref() {
+            result = result
+                .leg
+                .add_throw(throw)
+                .map_err(AddThrowsError::InvalidThrow)?;
+
+            if result.state != State::Unfinished {
+                let remaining_throws: Vec<Throw> = throws.collect();
+
+                if remaining_throws.is_empty() {
+                    return Ok(result);
+                }
+
+                return Err(AddThrowsError::EarlyFinish(EarlyFinish {
+                    remaining_throws,
+                    result,
+                }));
+            }
+
+            if result.leg.current.turn.num_throws() == 0 {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Step through a recorded leg throw by throw
+///
+/// Every step replays the recorded throws from the start of the leg.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegReplay<'a> {
+    ruleset: &'a Ruleset,
+    participants: &'a Participants,
+    first_player: usize,
+    throws: Vec<Throw>,
+    position: usize,
+}
+
+impl<'a> LegReplay<'a> {
+    /// Create a replay positioned before the first throw
+    ///
+    /// Fails if the throws are not a valid leg.
+    pub fn new(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        first_player: usize,
+        throws: Vec<Throw>,
+    ) -> Result<Self, LegError> {
+        Leg::from_throws(ruleset, participants, first_player, &throws)?;
+
+        Ok(LegReplay {
+            ruleset,
+            participants,
+            first_player,
+            throws,
+            position: 0,
+        })
+    }
+
+    /// Number of throws replayed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of recorded throws
+    pub fn len(&self) -> usize {
+        self.throws.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.throws.is_empty()
+    }
+
+    /// The leg after the throws up to the current position
+    pub fn leg(&self) -> Leg<'a> {
+        Leg::from_throws(
+            self.ruleset,
+            self.participants,
+            self.first_player,
+            &self.throws[..self.position],
+        )
+        .expect("recorded throws were validated on creation")
+    }
+
+    /// Replay the next throw, `None` if all throws were replayed
+    pub fn step_forward(&mut self) -> Option<Leg<'a>> {
+        if self.position == self.throws.len() {
+            return None;
+        }
+
+        self.position += 1;
+        Some(self.leg())
+    }
+
+    /// Take back the last replayed throw, `None` if no throw was replayed
+    pub fn step_back(&mut self) -> Option<Leg<'a>> {
+        if self.position == 0 {
+            return None;
+        }
+
+        self.position -= 1;
+        Some(self.leg())
+    }
+}
+
+/// Drive a leg throw by throw without rebinding it after every throw
+///
+/// A throw that is rejected leaves the leg as it was before the throw.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegDriver<'a> {
+    result: ThrowResult<'a>,
+}
+
+impl<'a> LegDriver<'a> {
+    pub fn new(leg: Leg<'a>) -> Self {
+        LegDriver {
+            result: ThrowResult::unfinished(leg),
+        }
+    }
+
+    /// Add the throw to the leg and return the result of it
+    pub fn throw(&mut self, throw: Throw) -> Result<&ThrowResult<'a>, LegError> {
+        if self.finished() {
+            return Err(LegError::ThrowsAfterFinish(1));
+        }
+
+        self.result = self.result.leg.clone().add_throw(throw)?;
+        Ok(&self.result)
+    }
+
+    pub fn leg(&self) -> &Leg<'a> {
+        &self.result.leg
+    }
+
+    pub fn current_turn(&self) -> &Turn {
+        self.result.leg.current_turn()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.result.state != State::Unfinished
+    }
+
+    /// The result of the last throw
+    pub fn into_result(self) -> ThrowResult<'a> {
+        self.result
+    }
+}
+
+/// An error that occurs when building a leg with [LegBuilder]
+#[derive(Debug, PartialEq, Eq)]
+pub enum LegBuilderError {
+    InvalidScore(InvalidScoreError),
+    InvalidPlayer(NewPlayerError),
+    NoPlayers,
+}
+
+impl Error for LegBuilderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for LegBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LegBuilderError::InvalidScore(err) => write!(f, "{}", err),
+            LegBuilderError::InvalidPlayer(err) => write!(f, "{}", err),
+            LegBuilderError::NoPlayers => writeln!(f, "A leg needs at least one player"),
+        }
+    }
+}
+
+/// Fluent setup of a leg without building the [Ruleset] and [Participants] separately
+#[derive(Clone, Debug)]
+pub struct LegBuilder {
+    score: u32,
+    in_rule: InRule,
+    out_rule: OutRule,
+    players: Vec<String>,
+}
+
+impl Default for LegBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LegBuilder {
+    /// Start with a 501 leg without in and out rules
+    pub fn new() -> Self {
+        LegBuilder {
+            score: 501,
+            in_rule: InRule::Any,
+            out_rule: OutRule::Any,
+            players: vec![],
+        }
+    }
+
+    pub fn score(mut self, score: u32) -> Self {
+        self.score = score;
+        self
+    }
+
+    pub fn in_rule(mut self, in_rule: InRule) -> Self {
+        self.in_rule = in_rule;
+        self
+    }
+
+    pub fn out_rule(mut self, out_rule: OutRule) -> Self {
+        self.out_rule = out_rule;
+        self
+    }
+
+    pub fn double_in(self) -> Self {
+        self.in_rule(InRule::Double)
+    }
+
+    pub fn double_out(self) -> Self {
+        self.out_rule(OutRule::Double)
+    }
+
+    /// Add a player with the given name, players throw in the order they are added
+    pub fn player(mut self, name: &str) -> Self {
+        self.players.push(name.to_owned());
+        self
+    }
+
+    pub fn build(self) -> Result<LegSetup, LegBuilderError> {
+        let score = is_valid_score(self.score).map_err(LegBuilderError::InvalidScore)?;
+
+        let players = self
+            .players
+            .iter()
+            .map(|name| Player::new(name))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(LegBuilderError::InvalidPlayer)?;
+
+        let participants = Participants::new()
+            .extend(players)
+            .build()
+            .ok_or(LegBuilderError::NoPlayers)?;
+
+        let ruleset = Ruleset::new()
+            .score(score)
+            .expect("score was validated")
+            .in_rule(self.in_rule)
+            .out_rule(self.out_rule)
+            .build();
+
+        Ok(LegSetup {
+            ruleset,
+            participants,
+        })
+    }
+}
+
+/// Owns the ruleset and participants created by [LegBuilder]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegSetup {
+    pub ruleset: Ruleset,
+    pub participants: Participants,
+}
+
+impl LegSetup {
+    /// Start a new leg with the first player
+    pub fn leg(&self) -> Leg<'_> {
+        Leg::new(&self.ruleset, &self.participants, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use proptest::prelude::*;
+
+    use crate::clock::Clock;
+    use crate::player::Player;
+    use crate::throw::Throw;
+    use crate::turn::{ThrowError, Turn, MAX_THROWS};
+    use crate::x01::leg::State;
+    use crate::x01::participants::{test_participants, test_teams, Participants, RetireError};
+    use crate::x01::ruleset::{BustRule, InRule, OutRule};
+    use crate::x01::{leg::ThrowResult, ruleset::Ruleset};
+
+    use super::{
+        AddThrowsError, CreateLegError, EarlyFinish, InvalidScoreError, Leg, LegBuilder,
+        LegBuilderError, LegDriver, LegError, LegReplay, RestoreLegError, UnpackLegError,
+        FORFEIT_BYTE,
+    };
+    use crate::throw::InvalidThrowError;
+
+    #[test]
+    fn simple_game() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+
+        let first_throw = Throw::triple(20).unwrap();
+        let second_throw = Throw::double(20).unwrap();
+        let third_throw = Throw::single(1).unwrap();
+
+        let ThrowResult { state, leg } = leg.add_throw(first_throw).unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.current_points(), 41);
+
+        let ThrowResult { state, leg } = leg.add_throw(second_throw).unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.current_points(), 1);
+
+        let ThrowResult { state, leg } = leg.add_throw(third_throw).unwrap();
+
+        assert_eq!(
+            state,
+            State::Finished {
+                winner: 0,
+                darts: 3
+            }
+        );
+        assert_eq!(leg.current_points(), 0);
+    }
+
+    #[test]
+    fn simple_game_is_driven_without_rebinding() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+        let mut driver = LegDriver::new(Leg::new(&ruleset, &participants, 0));
+
+        let throws = [
+            Throw::triple(20).unwrap(),
+            Throw::double(20).unwrap(),
+            Throw::single(1).unwrap(),
+        ];
+
+        for throw in throws {
+            assert!(!driver.finished());
+            driver.throw(throw).unwrap();
+        }
+
+        assert!(driver.finished());
+        assert_eq!(driver.current_turn().num_throws(), 3);
+        assert_eq!(
+            driver.throw(Throw::single(1).unwrap()),
+            Err(LegError::ThrowsAfterFinish(1))
+        );
+        assert_eq!(
+            driver.into_result().state,
+            State::Finished {
+                winner: 0,
+                darts: 3
+            }
+        );
+    }
+
+    #[test]
+    fn rejected_throw_keeps_driven_leg() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        leg.current.turn.bust();
+        let mut driver = LegDriver::new(leg);
+
+        assert_eq!(
+            driver.throw(Throw::triple(20).unwrap()),
+            Err(LegError::InvalidTurn(ThrowError::Bust))
+        );
+        assert!(driver.current_turn().is_bust());
+        assert_eq!(driver.leg().current_points(), 101);
+    }
+
+    #[test]
+    fn leg_is_replayed_from_throws() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let throws = [
+            Throw::triple(20).unwrap(),
+            Throw::double(20).unwrap(),
+            Throw::single(1).unwrap(),
+        ];
+
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.current_points(), 0);
+        assert_eq!(leg.winner(), Some(0));
+    }
+
+    #[test]
+    fn replaying_throws_after_finish_fails() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let throws = [
+            Throw::triple(20).unwrap(),
+            Throw::double(20).unwrap(),
+            Throw::single(1).unwrap(),
+            Throw::miss().unwrap(),
+        ];
+
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws);
+
+        assert_eq!(leg, Err(LegError::ThrowsAfterFinish(1)));
+    }
+
+    #[test]
+    fn teams_share_score_and_alternate_throwers() {
+        let participants = test_teams();
+
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let t20 = Throw::triple(20).unwrap();
+        let miss = Throw::miss().unwrap();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        let mut throwers = vec![];
+
+        for throw in [&t20, &miss, &t20, &miss] {
+            throwers.push(leg.current_player().name().to_owned());
+
+            for _ in 0..3 {
+                leg = leg.add_throw(throw.clone()).unwrap().leg;
+            }
+        }
+
+        assert_eq!(throwers, ["Anna", "Bob", "Pete", "Carl"]);
+        assert_eq!(leg.current_player().name(), "Anna");
+        assert_eq!(leg.current_points(), 141);
+    }
+
+    #[derive(Debug, Default)]
+    struct MockClock {
+        now: Cell<Duration>,
+    }
+
+    impl MockClock {
+        fn advance(&self, seconds: u64) {
+            self.now.set(self.now.get() + Duration::from_secs(seconds));
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn average_turn_duration_is_measured() {
+        let participants = test_participants(2);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let clock = MockClock::default();
+        let mut leg = Leg::new(&ruleset, &participants, 0).with_clock(&clock);
+
+        for seconds in [10, 5, 20, 7] {
+            clock.advance(seconds);
+            for _ in 0..3 {
+                leg = leg.add_throw(Throw::miss().unwrap()).unwrap().leg;
+            }
+        }
+
+        assert_eq!(leg.average_turn_duration(0), Some(Duration::from_secs(15)));
+        assert_eq!(leg.average_turn_duration(1), Some(Duration::from_secs(6)));
+    }
+
+    #[test]
+    fn turns_are_not_timed_without_clock() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+
+        for _ in 0..3 {
+            leg = leg.add_throw(Throw::miss().unwrap()).unwrap().leg;
+        }
+
+        assert_eq!(leg.average_turn_duration(0), None);
+    }
+
+    #[test]
+    fn finishing_on_nominated_throw_wins() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &[Throw::triple(20).unwrap()]);
+        let mut leg = leg.unwrap();
+        leg.nominate(Throw::double(16).unwrap());
+
+        let ThrowResult { state: _, leg } = leg.add_throw(Throw::single(9).unwrap()).unwrap();
+        let ThrowResult { state, leg } = leg.add_throw(Throw::double(16).unwrap()).unwrap();
+
+        assert_eq!(
+            state,
+            State::Finished {
+                winner: 0,
+                darts: 3
+            }
+        );
+        assert_eq!(leg.winner(), Some(0));
+    }
+
+    #[test]
+    fn finishing_on_other_throw_than_nominated_busts() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let throws = [Throw::triple(20).unwrap(), Throw::single_bull().unwrap()];
+        let mut leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+        leg.nominate(Throw::double(16).unwrap());
+
+        let ThrowResult { state, leg } = leg.add_throw(Throw::double(8).unwrap()).unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert!(leg.data[0].turns[0].is_bust());
+        assert_eq!(leg.current_points(), 101);
+    }
+
+    #[test]
+    fn double_bull_finishes_double_out() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let leg =
+            Leg::from_throws(&ruleset, &participants, 0, &[Throw::triple(17).unwrap()]).unwrap();
+
+        let ThrowResult { state, .. } = leg.add_throw(Throw::double_bull().unwrap()).unwrap();
+
+        assert_eq!(
+            state,
+            State::Finished {
+                winner: 0,
+                darts: 2
+            }
+        );
+    }
+
+    #[test]
+    fn single_bull_busts_double_out() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let throws = [Throw::triple(20).unwrap(), Throw::double(8).unwrap()];
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        let ThrowResult { state, leg } = leg.add_throw(Throw::single_bull().unwrap()).unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert!(leg.data[0].turns[0].is_bust());
+        assert_eq!(leg.current_points(), 101);
+    }
+
+    #[test]
+    fn forfeited_turn_records_no_darts() {
+        let participants = test_participants(2);
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+
+        let ThrowResult { state, leg } = leg.forfeit_turn().unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.current_player().name(), "Pete");
+        assert_eq!(leg.data[0].turns[0].num_throws(), 0);
+        assert!(leg.data[0].turns[0].is_forfeited());
+        assert!(!leg.data[0].turns[0].is_bust());
+    }
+
+    #[test]
+    fn retired_players_are_skipped() {
+        let mut participants = Participants::new()
+            .add(&Player::new("Anna").unwrap())
+            .add(&Player::new("Pete").unwrap())
+            .add(&Player::new("Bob").unwrap())
+            .build()
+            .unwrap();
+        participants.retire(1).unwrap();
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        let mut throwers = vec![];
+
+        for _ in 0..3 {
+            throwers.push(leg.current_player().name().to_owned());
+            leg = leg.forfeit_turn().unwrap().leg;
+        }
+
+        assert_eq!(throwers, ["Anna", "Bob", "Anna"]);
+        assert_eq!(leg.winner(), None);
+    }
+
+    #[test]
+    fn last_active_player_wins() {
+        let mut participants = test_participants(2);
+        participants.retire(0).unwrap();
+
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+
+        assert_eq!(leg.current_player().name(), "Pete");
+        assert_eq!(leg.winner(), Some(1));
+        assert_eq!(leg.checkouts(1), 0);
+    }
+
+    #[test]
+    fn players_retire_from_running_leg() {
+        let participants = Participants::new()
+            .add(&Player::new("Anna").unwrap())
+            .add(&Player::new("Pete").unwrap())
+            .add(&Player::new("Bob").unwrap())
+            .build()
+            .unwrap();
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::triple(20).unwrap()).unwrap();
+
+        let ThrowResult { state, mut leg } = leg.retire(1).unwrap();
+        assert_eq!(state, State::Unfinished);
+        assert!(leg.is_retired(1));
+
+        let mut throwers = vec![];
+        for _ in 0..3 {
+            throwers.push(leg.current_player().name().to_owned());
+            leg = leg.forfeit_turn().unwrap().leg;
+        }
+        assert_eq!(throwers, ["Anna", "Bob", "Anna"]);
+
+        // Bob retires during his own turn, which leaves Anna as the winner
+        let ThrowResult { state, leg } = leg.retire(2).unwrap();
+        assert!(matches!(state, State::Finished { winner: 0, .. }));
+        assert_eq!(leg.winner(), Some(0));
+        assert_eq!(leg.active_count(), 1);
+    }
+
+    #[test]
+    fn retiring_from_leg_is_validated() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+        let leg = Leg::new(&ruleset, &participants, 0);
+
+        assert_eq!(
+            leg.clone().retire(2),
+            Err(LegError::InvalidRetirement(RetireError::IndexOutOfRange(2)))
+        );
+
+        let single = test_participants(1);
+        assert_eq!(
+            Leg::new(&ruleset, &single, 0).retire(0),
+            Err(LegError::InvalidRetirement(RetireError::LastActive(0)))
+        );
+
+        let ThrowResult { leg, .. } = leg.retire(1).unwrap();
+        assert_eq!(
+            leg.retire(1),
+            Err(LegError::InvalidRetirement(RetireError::AlreadyRetired(1)))
+        );
+    }
+
+    #[test]
+    fn leg_starts_with_given_first_player() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new_with_first_player(&ruleset, &participants, 1).unwrap();
+        assert_eq!(leg.current_player().name(), "Pete");
+        assert_eq!(leg.player_count(), 2);
+        assert!(leg.is_multiplayer());
+        assert!(!leg.is_single_player());
+
+        assert_eq!(
+            Leg::new_with_first_player(&ruleset, &participants, 2),
+            Err(CreateLegError::InvalidFirstPlayer(2))
+        );
+    }
+
+    #[test]
+    fn throw_result_is_displayed() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let result = leg.add_throw(Throw::triple(20).unwrap()).unwrap();
+        assert_eq!(result.to_string(), "Unfinished: Anna on 41");
+
+        let leg = result.leg;
+        let leg = leg.add_throw(Throw::double(20).unwrap()).unwrap().leg;
+        let result = leg.add_throw(Throw::single(1).unwrap()).unwrap();
+        assert_eq!(result.to_string(), "Finished!");
+    }
+
+    #[test]
+    fn leader_is_player_with_lowest_committed_score() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::from_throws(
+            &ruleset,
+            &participants,
+            0,
+            &vec![Throw::triple(20).unwrap(); 4],
+        )
+        .unwrap();
+
+        // Pete has thrown one dart of his turn, which is not committed yet
+        assert_eq!(leg.leader(), Some((0, 180)));
+    }
+
+    #[test]
+    fn retired_players_do_not_lead() {
+        let participants = Participants::new()
+            .add(&Player::new("Anna").unwrap())
+            .add(&Player::new("Pete").unwrap())
+            .add(&Player::new("Bob").unwrap())
+            .build()
+            .unwrap();
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let throws = [
+            vec![Throw::triple(20).unwrap(); 3],
+            vec![Throw::single(20).unwrap(); 3],
+            vec![Throw::single(1).unwrap(); 3],
+        ]
+        .concat();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+        assert_eq!(leg.leader(), Some((0, 120)));
+
+        let leg = leg.retire(0).unwrap().leg;
+        assert_eq!(leg.leader(), Some((1, 57)));
+    }
+
+    #[test]
+    fn tied_leg_has_no_leader() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::from_throws(
+            &ruleset,
+            &participants,
+            0,
+            &vec![Throw::triple(20).unwrap(); 6],
+        )
+        .unwrap();
+
+        assert_eq!(leg.leader(), None);
+        assert_eq!(Leg::new(&ruleset, &participants, 0).leader(), None);
+    }
+
+    #[test]
+    fn throw_into_busted_turn_is_reported_as_error() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        leg.current.turn.bust();
+
+        assert_eq!(
+            leg.add_throw(Throw::miss().unwrap()),
+            Err(LegError::InvalidTurn(ThrowError::Bust))
+        );
+    }
+
+    #[test]
+    fn leg_is_restored_from_checkpoint() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let t20 = Throw::triple(20).unwrap();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &vec![t20.clone(); 4]).unwrap();
+        let checkpoint = leg.checkpoint();
+
+        let later = leg.clone().add_throw(t20).unwrap().leg;
+        assert_ne!(later, leg);
+
+        assert_eq!(Leg::restore(&ruleset, &participants, checkpoint), Ok(leg));
+    }
+
+    #[test]
+    fn invalid_checkpoints_are_rejected() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg =
+            Leg::from_throws(&ruleset, &participants, 0, &[Throw::triple(20).unwrap()]).unwrap();
+        let restore = |checkpoint| Leg::restore(&ruleset, &participants, checkpoint);
+
+        let mut checkpoint = leg.checkpoint();
+        checkpoint.data.pop();
+        assert_eq!(
+            restore(checkpoint),
+            Err(RestoreLegError::WrongParticipantCount(1))
+        );
+
+        let mut checkpoint = leg.checkpoint();
+        checkpoint.current.index = 2;
+        assert_eq!(restore(checkpoint), Err(RestoreLegError::InvalidPlayer(2)));
+
+        let mut checkpoint = leg.checkpoint();
+        for _ in 0..MAX_THROWS {
+            checkpoint.current.turn.add_throw(Throw::Miss).unwrap();
+        }
+        assert_eq!(restore(checkpoint), Err(RestoreLegError::InvalidTurn(0)));
+
+        let mut checkpoint = leg.checkpoint();
+        checkpoint.current.points = 41;
+        assert_eq!(restore(checkpoint), Err(RestoreLegError::InvalidScore(0)));
+    }
+
+    #[test]
+    fn points_per_dart_include_busted_darts() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        assert_eq!(Leg::new(&ruleset, &participants, 0).points_per_dart(0), 0.0);
+
+        let throws: Vec<_> = ["T20", "T20", "T20", "D20", "1"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        // The busted first turn took two darts, the second turn scored all 101 points
+        assert!((leg.points_per_dart(0) - 101.0 / 5.0).abs() < 1e-9);
+        assert!((leg.three_dart_average(0) - 3.0 * leg.points_per_dart(0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solo_leg_is_completed_with_stats() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        assert!(leg.is_single_player());
+
+        let throws = [
+            "T20", "T20", "T20", "T20", "T20", "T20", "T20", "T19", "D12",
+        ];
+        let mut states = vec![];
+
+        for throw in throws {
+            let result = leg.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+            assert_eq!(result.leg.current_player().name(), "Anna");
+            states.push(result.state);
+            leg = result.leg;
+        }
+
+        assert_eq!(
+            states.last(),
+            Some(&State::Finished {
+                winner: 0,
+                darts: 9
+            })
+        );
+        assert_eq!(leg.winner(), Some(0));
+        assert_eq!(leg.darts_thrown(0), 9);
+        assert_eq!(leg.maximums(0), 2);
+        assert_eq!(leg.checkout(0), Some(141));
+        assert!((leg.three_dart_average(0) - 167.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn finished_leg_is_summarized() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let throws: Vec<_> = ["T20", "1", "1", "T20", "D20", "0", "D19", "1"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws[..7]).unwrap();
+
+        assert_eq!(leg.summary(), None);
+
+        let leg = leg.add_throw(throws[7].clone()).unwrap().leg;
+        let summary = leg.summary().unwrap();
+
+        assert_eq!(summary.winner.name(), "Anna");
+        assert_eq!(summary.darts_thrown_by_winner, 5);
+        assert!((summary.three_dart_average_by_winner - 101.0 / 5.0 * 3.0).abs() < 1e-9);
+        assert_eq!(summary.highest_turn, 100);
+        assert_eq!(summary.total_turns, 3);
+        assert_eq!(summary.checkout_throw, Throw::single(1).unwrap());
+    }
+
+    #[test]
+    fn all_throws_are_in_chronological_order() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let throws: Vec<_> = ["T20", "1", "5", "D20", "T19", "0", "20", "17"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 1, &throws).unwrap();
+
+        let players: Vec<_> = leg.all_throws().map(|(index, _)| index).collect();
+        let all: Vec<_> = leg.all_throws().map(|(_, throw)| throw.clone()).collect();
+
+        assert_eq!(players, [1, 1, 1, 0, 0, 0, 1, 1]);
+        assert_eq!(all, throws);
+    }
+
+    #[test]
+    fn leg_is_set_up_with_builder() {
+        let setup = LegBuilder::new()
+            .score(501)
+            .double_out()
+            .player("Anna")
+            .player("Pete")
+            .build()
+            .unwrap();
+
+        assert_eq!(*setup.ruleset.out_rule(), OutRule::Double);
+        assert_eq!(setup.participants.count(), 2);
+
+        let leg = setup
+            .leg()
+            .add_throw(Throw::triple(20).unwrap())
+            .unwrap()
+            .leg;
+
+        assert_eq!(leg.current_player().name(), "Anna");
+        assert_eq!(leg.current_points(), 441);
+    }
+
+    #[test]
+    fn leg_builder_rejects_invalid_setup() {
+        assert_eq!(
+            LegBuilder::new().player("Anna").score(1).build(),
+            Err(LegBuilderError::InvalidScore(InvalidScoreError::TooSmall(
+                1
+            )))
+        );
+        assert_eq!(LegBuilder::new().build(), Err(LegBuilderError::NoPlayers));
+        assert!(matches!(
+            LegBuilder::new().player(" ").build(),
+            Err(LegBuilderError::InvalidPlayer(_))
+        ));
+    }
+
+    #[test]
+    fn prospective_throws_are_checked_for_bust() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let throws = [Throw::triple(20).unwrap(), Throw::single(1).unwrap()];
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+        assert_eq!(leg.current_points(), 40);
+
+        // Overshooting or leaving 1 busts under double out
+        assert!(leg.would_bust(&Throw::triple(20).unwrap()));
+        assert!(leg.would_bust(&Throw::triple(13).unwrap()));
+        assert!(!leg.would_bust(&Throw::single(20).unwrap()));
+        assert!(!leg.would_bust(&Throw::double(20).unwrap()));
+        assert_eq!(leg.current_points(), 40);
+    }
+
+    #[test]
+    fn scoreboard_includes_turn_in_progress() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::from_throws(
+            &ruleset,
+            &participants,
+            0,
+            &vec![Throw::triple(20).unwrap(); 4],
+        )
+        .unwrap();
+
+        let mut scores = vec![];
+        leg.for_each_player(|player, points| scores.push((player.name().to_owned(), points)));
+
+        assert_eq!(
+            scores,
+            [(String::from("Anna"), 121), (String::from("Pete"), 241)]
+        );
+    }
+
+    proptest! {
+        /// `Turn` accepts any number of throws, the leg makes sure a turn ends after three
+        #[test]
+        fn turns_never_exceed_three_throws(
+            throws in prop::collection::vec(
+                prop::sample::select(Throw::all_throws().collect::<Vec<_>>()),
+                0..60,
+            )
+        ) {
+            let participants = test_participants(2);
+            let ruleset = Ruleset::new().score(501).unwrap().build();
+            let mut leg = Leg::new(&ruleset, &participants, 0);
+
+            for throw in throws {
+                let ThrowResult { state, leg: next } = leg.add_throw(throw).unwrap();
+                leg = next;
+
+                prop_assert!(leg.current_turn().num_throws() <= MAX_THROWS);
+                if state != State::Unfinished {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn turn_is_added_at_once() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let maximum = vec![Throw::triple(20).unwrap(); 3];
+
+        let ThrowResult { state, leg } = leg.add_turn(&maximum).unwrap();
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.remaining_for(0), Some(121));
+        assert_eq!(leg.current_player_index(), 1);
+
+        let leg = leg.add_turn(&[]).unwrap().leg;
+        assert_eq!(
+            leg.add_turn(&vec![Throw::Miss; 4]),
+            Err(LegError::TooManyThrows(4))
+        );
+    }
+
+    #[test]
+    fn turn_added_at_once_stops_at_finish() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(100).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let throws = [
+            Throw::triple(20).unwrap(),
+            Throw::double(20).unwrap(),
+            Throw::triple(20).unwrap(),
+        ];
+
+        let ThrowResult { state, leg } = leg.add_turn(&throws).unwrap();
+
+        assert_eq!(
+            state,
+            State::Finished {
+                winner: 0,
+                darts: 2
+            }
+        );
+        assert_eq!(leg.all_throws().count(), 2);
+    }
+
+    #[test]
+    fn leaving_one_busts_double_out() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::triple(20).unwrap()).unwrap();
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::single(20).unwrap()).unwrap();
+        assert_eq!(leg.current_points(), 21);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ThrowResult<'a> {
-    pub state: State,
-    pub leg: Leg<'a>,
-}
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::single(20).unwrap()).unwrap();
+        assert_eq!(leg.current_points(), 101);
 
-impl<'a> ThrowResult<'_> {
-    fn unfinished(leg: Leg) -> ThrowResult {
-        ThrowResult {
-            state: State::Unfinished,
-            leg,
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::triple(20).unwrap()).unwrap();
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::single(20).unwrap()).unwrap();
+        let ThrowResult { leg, .. } = leg.add_throw(Throw::single(19).unwrap()).unwrap();
+        assert_eq!(leg.current_points(), 2);
+    }
+
+    #[test]
+    fn throws_are_added_until_turn_end() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let throws = vec![Throw::triple(20).unwrap(); 5];
+
+        let ThrowResult { state, leg } = leg.add_throws_until_turn_end(throws).unwrap();
+
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.remaining_for(0), Some(121));
+        assert_eq!(leg.current_player_index(), 1);
+        assert_eq!(leg.all_throws().count(), 3);
+    }
+
+    #[test]
+    fn throws_after_finish_are_returned() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(100).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let t20 = Throw::triple(20).unwrap();
+        let d20 = Throw::double(20).unwrap();
+
+        let finished = leg
+            .clone()
+            .add_throws_until_turn_end([t20.clone(), d20.clone()])
+            .unwrap();
+        assert_eq!(
+            finished.state,
+            State::Finished {
+                winner: 0,
+                darts: 2
+            }
+        );
+
+        let Err(AddThrowsError::EarlyFinish(EarlyFinish {
+            remaining_throws,
+            result,
+        })) = leg.add_throws_until_turn_end([t20.clone(), d20, t20.clone()])
+        else {
+            panic!("leg should finish early");
+        };
+
+        assert_eq!(remaining_throws, vec![t20]);
+        assert_eq!(result, finished);
+    }
+
+    #[test]
+    fn first_turn_of_leg_is_detected_per_player() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .in_rule(InRule::Double)
+            .build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        assert!(leg.is_first_turn_of_leg());
+
+        leg = leg.add_throw(Throw::double(20).unwrap()).unwrap().leg;
+        assert!(!leg.is_first_turn_of_leg());
+
+        for _ in 0..2 {
+            leg = leg.add_throw(Throw::Miss).unwrap().leg;
         }
+        assert!(leg.is_first_turn_of_leg());
     }
-    fn finished(leg: Leg) -> ThrowResult {
-        ThrowResult {
-            state: State::Finished,
-            leg,
+
+    #[test]
+    fn current_player_index_follows_turns() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 1);
+        assert_eq!(leg.current_player_index(), 1);
+
+        for _ in 0..3 {
+            leg = leg.add_throw(Throw::Miss).unwrap().leg;
         }
+        assert_eq!(leg.current_player_index(), 0);
     }
-}
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct ParticipantData {
-    turns: Vec<Turn>,
-}
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Leg<'a> {
-    ruleset: &'a Ruleset,
-    participants: &'a Participants,
-    current: CurrentPlayer,
-    data: Vec<ParticipantData>,
-}
+    #[test]
+    fn checkout_darts_count_the_finishing_turn() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
 
-impl<'a> Leg<'a> {
-    fn calculate_score(&self, player_index: usize, start_score: u32) -> Option<u32> {
-        let sum = self.data[player_index]
-            .turns
+        let throws: Vec<Throw> = ["T20", "1", "0", "T20", "0", "0", "20", "D10"]
             .iter()
-            .filter_map(|turn| {
-                if turn.is_bust() {
-                    None
-                } else {
-                    Some(turn.points() as u32)
-                }
-            })
-            .sum();
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
 
-        start_score.checked_sub(sum)
+        assert_eq!(leg.winner(), Some(0));
+        assert_eq!(leg.checkout_darts(0), Some(2));
+        assert_eq!(leg.checkout_darts(1), None);
     }
 
-    pub fn new(ruleset: &'a Ruleset, participants: &'a Participants, first_player: usize) -> Self {
-        let mut data = vec![];
+    #[test]
+    fn cloned_legs_advance_independently() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
 
-        for _ in 0..participants.count() {
-            data.push(ParticipantData { turns: vec![] })
-        }
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        leg = leg.add_throw(Throw::triple(20).unwrap()).unwrap().leg;
 
-        Self {
-            ruleset,
-            participants,
-            current: Default::default(),
-            data,
+        // Branch in the middle of a turn
+        let mut branch = leg.clone();
+        leg = leg.add_throw(Throw::triple(20).unwrap()).unwrap().leg;
+        branch = branch.add_throw(Throw::single(1).unwrap()).unwrap().leg;
+
+        assert_eq!(leg.current_points(), 181);
+        assert_eq!(branch.current_points(), 240);
+        assert_eq!(branch.current_turn().num_throws(), 2);
+
+        // Branch again in the turn of Pete
+        for _ in 0..2 {
+            leg = leg.add_throw(Throw::Miss).unwrap().leg;
+            branch = branch.add_throw(Throw::Miss).unwrap().leg;
         }
-        .begin_turn(first_player)
+        let other = leg.clone();
+        leg = leg.forfeit_turn().unwrap().leg;
+
+        assert_eq!(leg.current_player().name(), "Anna");
+        assert_eq!(other.current_player().name(), "Pete");
+        assert_eq!(other.current_turn().throws(), [Throw::Miss]);
+        assert_eq!(leg.remaining_for(0), Some(181));
+        assert_eq!(branch.remaining_for(0), Some(240));
     }
 
-    fn begin_turn(self, next_player: usize) -> Self {
-        // let participant = &self.participants.participants[next_player];
-        let points = self.calculate_score(next_player, *self.ruleset.score());
+    #[test]
+    fn hits_are_counted_per_throw() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(201).unwrap().build();
 
-        if let Some(points) = points {
-            Leg {
-                current: CurrentPlayer {
-                    index: next_player,
-                    points,
-                    turn: Turn::new(),
-                },
-                ..self
+        // The second turn busts, its darts are counted nonetheless
+        let throws: Vec<Throw> = ["T20", "T20", "20", "T20", "T20", "1", "T19"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+        assert!(leg.data[0].turns[1].is_bust());
+
+        let hits = leg.hit_distribution(0);
+
+        assert_eq!(hits.len(), 4);
+        assert_eq!(hits[&Throw::triple(20).unwrap()], 4);
+        assert_eq!(hits[&Throw::single(20).unwrap()], 1);
+        assert_eq!(hits[&Throw::single(1).unwrap()], 1);
+        assert_eq!(hits[&Throw::triple(19).unwrap()], 1);
+    }
+
+    #[test]
+    fn remaining_points_ignore_turn_in_progress() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let throws: Vec<Throw> = ["T20", "T20", "T20", "20", "20", "20", "T19"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.current_points(), 264);
+        assert_eq!(leg.remaining_for(0), Some(321));
+        assert_eq!(leg.remaining_for(1), Some(441));
+        assert_eq!(leg.remaining_for(2), None);
+    }
+
+    #[test]
+    fn no_bust_ignores_overshooting_darts() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .bust_rule(BustRule::NoBust)
+            .build();
+
+        let throws: Vec<Throw> = ["T20", "T20", "1"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.current_points(), 40);
+        assert_eq!(leg.data[0].turns[0].throws(), throws);
+        assert!(leg.data[0].turns[0].is_ignored(1));
+        assert!(!leg.data[0].turns[0].is_bust());
+        assert_eq!(leg.hit_distribution(0)[&Throw::triple(20).unwrap()], 2);
+        assert_eq!(
+            Leg::from_bytes(&ruleset, &participants, 0, &leg.to_bytes()),
+            Ok(leg.clone())
+        );
+
+        // Finishing on a single is ignored as well, the exact double wins
+        let leg = leg.add_throw(Throw::single(20).unwrap()).unwrap().leg;
+        let ThrowResult { state, leg } = leg.add_throw(Throw::single(20).unwrap()).unwrap();
+        assert_eq!(state, State::Unfinished);
+        assert_eq!(leg.current_points(), 20);
+
+        let ThrowResult { state, .. } = leg.add_throw(Throw::double(10).unwrap()).unwrap();
+        assert_eq!(
+            state,
+            State::Finished {
+                winner: 0,
+                darts: 6
             }
-        } else {
-            panic!("Invalid state reached")
-        }
+        );
     }
 
-    fn bust_turn(mut self) -> ThrowResult<'a> {
-        self.current.turn.bust();
-        self.next_turn()
+    #[test]
+    fn no_bust_ignores_darts_missing_the_in_rule() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .in_rule(InRule::Double)
+            .bust_rule(BustRule::NoBust)
+            .build();
+
+        let throws: Vec<Throw> = ["T20", "0", "T20", "D10", "T20"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        // The whole first turn missed the double, the second one got in with D10
+        let first = &leg.data[0].turns[0];
+        assert!(!first.is_bust());
+        assert!((0..3).all(|index| first.is_ignored(index)));
+        assert!(!leg.current_turn().is_ignored(0));
+        assert!(!leg.current_turn().is_ignored(1));
+        assert_eq!(leg.current_points(), 21);
     }
 
-    fn next_turn(mut self) -> ThrowResult<'a> {
-        let turn = std::mem::take(&mut self.current.turn);
-        self.data[self.current.index].turns.push(turn);
-        let next_player = (self.current.index + 1) % self.participants.participants.len();
-        ThrowResult::unfinished(Leg::begin_turn(self, next_player))
+    #[test]
+    fn leg_is_packed_into_bytes() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let throws: Vec<Throw> = ["T20", "D25", "m", "0", "5", "T19", "25"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        let bytes = leg.to_bytes();
+        assert_eq!(bytes.len(), throws.len());
+        assert_eq!(
+            Leg::from_bytes(&ruleset, &participants, 0, &bytes),
+            Ok(leg.clone())
+        );
+
+        let leg = leg.forfeit_turn().unwrap().leg;
+        let leg = leg.add_throw(Throw::single(1).unwrap()).unwrap().leg;
+        let leg = leg.forfeit_turn().unwrap().leg;
+
+        let bytes = leg.to_bytes();
+        assert_eq!(bytes[throws.len()], FORFEIT_BYTE);
+        assert_eq!(bytes.len(), throws.len() + 3);
+        assert_eq!(Leg::from_bytes(&ruleset, &participants, 0, &bytes), Ok(leg));
+        assert_eq!(
+            Leg::from_bytes(&ruleset, &participants, 0, &[5]),
+            Err(UnpackLegError::InvalidThrow(
+                InvalidThrowError::InvalidCode(5)
+            ))
+        );
     }
 
-    pub fn current_player(&self) -> &Player {
-        &self.participants.participants[self.current.index].player
+    #[test]
+    fn missed_darts_at_double_are_counted() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        // On 40 after two darts, misses the double, then leaves 20 and checks out
+        let throws: Vec<Throw> = ["T20", "1", "0", "20", "D10"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.winner(), Some(0));
+        assert_eq!(leg.darts_at_double(0), 2);
     }
 
-    pub fn current_points(&self) -> u32 {
-        self.current
-            .points
-            .checked_sub(self.current.turn.points().into())
+    #[test]
+    fn darts_until_checkout_are_counted() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(301)
             .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let throws: Vec<Throw> = ["T20", "T20", "T20", "T20", "1", "20"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws[..5]).unwrap();
+        assert_eq!(leg.throws_until_checkout_was_possible(0), None);
+
+        // 60 left after five darts, the sixth dart leaves 40
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+        assert_eq!(leg.throws_until_checkout_was_possible(0), Some(6));
     }
 
-    pub fn add_throw(mut self, throw: Throw) -> ThrowResult<'a> {
-        // Check if current throw results in new turn, win, continue turn, bust of turn
+    #[test]
+    fn players_are_ranked_by_remaining_points() {
+        let participants = Participants::new()
+            .add(&Player::new("Anna").unwrap())
+            .add(&Player::new("Pete").unwrap())
+            .add(&Player::new("Tina").unwrap())
+            .build()
+            .unwrap();
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let throws: Vec<Throw> = ["20", "0", "0", "T20", "T20", "0", "T20", "0", "0", "T20"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        // The turn Anna has in progress does not count
+        assert_eq!(leg.rankings(), [1, 2, 0]);
+    }
 
-        let first_throw =
-            self.data[self.current.index].turns.is_empty() && self.current.turn.num_throws() == 0;
-        self.current.turn.add_throw(throw.clone()).unwrap();
+    #[test]
+    fn replay_steps_forward_and_back() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
 
-        if first_throw && !self.ruleset.in_rule().valid_throw(&throw) {
-            return self.bust_turn();
-        }
+        let throws = ["T20", "T19", "20"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let mut replay = LegReplay::new(&ruleset, &participants, 0, throws).unwrap();
+
+        assert_eq!(replay.len(), 3);
+        assert_eq!(replay.leg().current_points(), 301);
+        assert_eq!(replay.step_back(), None);
+
+        let scores: Vec<u32> = (0..3)
+            .map(|_| replay.step_forward().unwrap().current_points())
+            .collect();
+        assert_eq!(scores, [241, 184, 164]);
+        assert_eq!(replay.step_forward(), None);
+
+        let leg = replay.step_back().unwrap();
+        assert_eq!(replay.position(), 2);
+        assert_eq!(leg.current_points(), 184);
+    }
 
-        let turn_points = self.current.turn.points();
+    #[test]
+    fn standings_only_count_committed_turns() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
 
-        match self.current.points.checked_sub(turn_points.into()) {
-            None => self.bust_turn(), // Player has thrown more points than remain
-            Some(points) => {
-                if points == 0 {
-                    if self.ruleset.out_rule().valid_finisher(&throw) {
-                        ThrowResult::finished(self)
-                    } else {
-                        self.bust_turn()
-                    }
-                } else {
-                    if self.ruleset.out_rule().valid_remaining_points(points) {
-                        if self.current.turn.num_throws() == 3 {
-                            self.next_turn()
-                        } else {
-                            ThrowResult::unfinished(self)
-                        }
-                    } else {
-                        self.bust_turn()
-                    }
-                }
-            }
+        let throws: Vec<Throw> = ["T20", "T20", "T20", "20", "20", "20", "T20"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        let standings: Vec<_> = leg
+            .standings()
+            .into_iter()
+            .map(|(player, points)| (player.name().to_string(), points))
+            .collect();
+
+        assert_eq!(
+            standings,
+            vec![("Anna".to_string(), 321), ("Pete".to_string(), 441)]
+        );
+    }
+
+    #[test]
+    fn all_player_scores_include_turn_in_progress() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        for throw in ["T20", "T20", "T20", "T19"] {
+            leg = leg.add_throw(Throw::from_str(throw).unwrap()).unwrap().leg;
         }
+
+        let scores: Vec<_> = leg
+            .all_player_scores()
+            .into_iter()
+            .map(|(player, points)| (player.name().to_string(), points))
+            .collect();
+
+        assert_eq!(
+            scores,
+            vec![("Anna".to_string(), 321), ("Pete".to_string(), 444)]
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::throw::Throw;
-    use crate::x01::leg::State;
-    use crate::x01::participants::test_participants;
-    use crate::x01::{leg::ThrowResult, ruleset::Ruleset};
+    #[test]
+    fn round_is_over_once_every_player_had_a_turn() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        assert_eq!(leg.current_round(), 1);
+
+        for _ in 0..3 {
+            leg = leg.add_throw(Throw::triple(20).unwrap()).unwrap().leg;
+        }
+        assert_eq!(leg.current_round(), 1);
 
-    use super::Leg;
+        for _ in 0..3 {
+            leg = leg.add_throw(Throw::triple(19).unwrap()).unwrap().leg;
+        }
+        assert_eq!(leg.current_round(), 2);
+    }
 
     #[test]
-    fn simple_game() {
-        let participants = test_participants(1);
+    fn retired_players_do_not_hold_back_the_round() {
+        let participants = Participants::new()
+            .add(&Player::new("Anna").unwrap())
+            .add(&Player::new("Pete").unwrap())
+            .add(&Player::new("Bob").unwrap())
+            .build()
+            .unwrap();
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        let mut leg = Leg::new(&ruleset, &participants, 0).retire(1).unwrap().leg;
+
+        for _ in 0..12 {
+            leg = leg.add_throw(Throw::single(1).unwrap()).unwrap().leg;
+        }
+        assert_eq!(leg.current_round(), 3);
+    }
 
+    #[test]
+    fn fourth_throw_in_uncommitted_turn_is_rejected() {
+        let participants = test_participants(1);
         let ruleset = Ruleset::new().score(101).unwrap().build();
 
-        let leg = Leg::new(&ruleset, &participants, 0);
-
-        let first_throw = Throw::triple(20).unwrap();
-        let second_throw = Throw::double(20).unwrap();
-        let third_throw = Throw::single(1).unwrap();
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        leg.current.turn = Turn::from_throws(vec![Throw::miss().unwrap(); 3], false).unwrap();
 
-        let ThrowResult { state, leg } = leg.add_throw(first_throw);
+        assert_eq!(
+            leg.add_throw(Throw::miss().unwrap()),
+            Err(LegError::TurnComplete)
+        );
+    }
 
-        assert_eq!(state, State::Unfinished);
-        assert_eq!(leg.current_points(), 41);
+    #[test]
+    fn corrupted_score_is_reported_as_error() {
+        let participants = test_participants(1);
 
-        let ThrowResult { state, leg } = leg.add_throw(second_throw);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
 
-        assert_eq!(state, State::Unfinished);
-        assert_eq!(leg.current_points(), 1);
+        let mut leg = Leg::new(&ruleset, &participants, 0);
+        let t20 = Throw::triple(20).unwrap();
+        leg.data[0]
+            .turns
+            .push(Turn::from_throws(vec![t20; 2], false).unwrap());
 
-        let ThrowResult { state, leg } = leg.add_throw(third_throw);
+        let miss = Throw::miss().unwrap();
+        let leg = leg.add_throw(miss.clone()).unwrap().leg;
+        let leg = leg.add_throw(miss.clone()).unwrap().leg;
 
-        assert_eq!(state, State::Finished);
-        assert_eq!(leg.current_points(), 0);
+        assert_eq!(leg.add_throw(miss), Err(LegError::InvalidScore(0)));
     }
 
     #[test]
@@ -216,7 +2815,7 @@ mod tests {
             let ThrowResult {
                 state: _,
                 leg: new_turn,
-            } = game.add_throw(miss.clone());
+            } = game.add_throw(miss.clone()).unwrap();
 
             game = new_turn;
         }
@@ -237,12 +2836,12 @@ mod tests {
         let miss = Throw::miss().unwrap();
         let d20 = Throw::double(20).unwrap();
 
-        let ThrowResult { state: _, leg } = leg.add_throw(d20.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(d20.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 61);
 
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 61);
     }
@@ -258,18 +2857,18 @@ mod tests {
         let miss = Throw::miss().unwrap();
         let d20 = Throw::double(20).unwrap();
 
-        let ThrowResult { state: _, leg } = leg.add_throw(d20.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(d20.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 61);
 
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 101);
 
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(miss.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 61);
     }
@@ -284,8 +2883,8 @@ mod tests {
 
         let t20 = Throw::triple(20).unwrap();
 
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
 
         assert_eq!(
             leg.current_player().name(),
@@ -303,11 +2902,63 @@ mod tests {
 
         let t20 = Throw::triple(20).unwrap();
 
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
 
         assert_eq!(leg.data[0].turns.len(), 1);
-        assert_eq!(leg.data[0].turns[0].is_bust(), true);
+        assert!(leg.data[0].turns[0].is_bust());
+    }
+
+    #[test]
+    fn checkout_attempts_are_counted() {
+        let participants = test_participants(1);
+
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+
+        let ThrowResult { state: _, leg } = leg.add_throw(Throw::triple(20).unwrap()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(Throw::single(1).unwrap()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(Throw::miss().unwrap()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(Throw::double(20).unwrap()).unwrap();
+
+        assert_eq!(leg.checkout_attempts(0), 2);
+        assert_eq!(leg.checkouts(0), 1);
+        assert_eq!(leg.winner(), Some(0));
+    }
+
+    #[test]
+    fn bust_resets_to_turn_start_by_default() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let t20 = Throw::triple(20).unwrap();
+        let one = Throw::single(1).unwrap();
+        let throws = [t20.clone(), one.clone(), one, t20];
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.current_points(), 39);
+    }
+
+    #[test]
+    fn bust_resets_to_start_score_with_reset_to_start_rule() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .bust_rule(BustRule::ResetToStart)
+            .build();
+
+        let t20 = Throw::triple(20).unwrap();
+        let one = Throw::single(1).unwrap();
+        let throws = [t20.clone(), one.clone(), one, t20];
+        let leg = Leg::from_throws(&ruleset, &participants, 0, &throws).unwrap();
+
+        assert_eq!(leg.current_points(), 101);
     }
 
     #[test]
@@ -320,8 +2971,8 @@ mod tests {
 
         let t20 = Throw::triple(20).unwrap();
 
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
-        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone());
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
+        let ThrowResult { state: _, leg } = leg.add_throw(t20.clone()).unwrap();
 
         assert_eq!(leg.current_points(), 101);
     }
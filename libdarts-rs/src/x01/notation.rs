@@ -0,0 +1,602 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{
+    player::{NewPlayerError, Player},
+    throw::{InvalidThrowError, Throw},
+};
+
+use super::{
+    leg::{Leg, LegError, State, ThrowResult},
+    participants::Participants,
+    ruleset::{
+        is_score_within, is_valid_score, BustRule, InRule, OutRule, Ruleset, DEFAULT_MAX_SCORE,
+    },
+};
+
+/// Marks the throw that finished the leg
+const FINISH_MARKER: char = '*';
+
+/// Marks a forfeited turn
+const FORFEIT_MARKER: &str = "-";
+
+/// Separates the members of a team in the header
+const TEAM_SEPARATOR: &str = " & ";
+
+/// Characters with a meaning in the notation, they are escaped with a backslash in names
+const ESCAPED_CHARS: [char; 5] = ['\\', '"', '&', '|', ':'];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A header line is malformed or unknown
+    InvalidHeader(String),
+    /// The score header is missing or not a valid score
+    InvalidScore,
+    /// The notation does not contain any players
+    NoPlayers,
+    InvalidPlayer(NewPlayerError),
+    InvalidThrow(InvalidThrowError),
+    /// A turn line is malformed
+    InvalidTurn(String),
+    /// A turn was recorded for a player that is not next to throw
+    UnexpectedPlayer(String),
+    /// Throws were recorded after the leg was finished
+    ThrowsAfterFinish,
+    /// Replaying the throws failed
+    InvalidLeg(LegError),
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidHeader(line) => writeln!(f, "Invalid header '{}'", line),
+            ParseError::InvalidScore => writeln!(f, "Missing or invalid score"),
+            ParseError::NoPlayers => writeln!(f, "No players in notation"),
+            ParseError::InvalidPlayer(err) => write!(f, "{}", err),
+            ParseError::InvalidThrow(err) => write!(f, "{}", err),
+            ParseError::InvalidTurn(turn) => writeln!(f, "Invalid turn '{}'", turn),
+            ParseError::UnexpectedPlayer(name) => {
+                writeln!(f, "'{}' is not the player to throw next", name)
+            }
+            ParseError::ThrowsAfterFinish => writeln!(f, "Throws recorded after leg finished"),
+            ParseError::InvalidLeg(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// A leg read from its PGN like notation
+///
+/// The notation starts with a header block followed by an empty line and one line per round:
+///
+/// ```text
+/// [Score "101"]
+/// [In "Any"]
+/// [Out "Double"]
+/// [Bust "Reset Turn"]
+/// [Player "Anna"]
+/// [Player "Pete"]
+///
+/// 1. Anna: T20 D20 1 | Pete: T19 T19 T19
+/// 2. Anna: D20*
+/// ```
+///
+/// The throw that finished the leg is marked with `*` and forfeited turns end with `-`. Teams are listed as
+/// `[Team "Anna & Pete"]` and each turn is recorded under the member who threw it. A `\`, `"`, `&`,
+/// `|` or `:` in a name is escaped with a backslash. A ruleset with a lower maximum than
+/// [DEFAULT_MAX_SCORE] adds a `[MaxScore "1001"]` header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notation {
+    pub ruleset: Ruleset,
+    pub participants: Participants,
+    turns: Vec<RecordedTurn>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct RecordedTurn {
+    name: String,
+    throws: Vec<Throw>,
+    forfeited: bool,
+}
+
+fn parse_in_rule(value: &str) -> Option<InRule> {
+    match value {
+        "Any" => Some(InRule::Any),
+        "Double" => Some(InRule::Double),
+        "Triple" => Some(InRule::Triple),
+        _ => None,
+    }
+}
+
+fn parse_out_rule(value: &str) -> Option<OutRule> {
+    match value {
+        "Any" => Some(OutRule::Any),
+        "Double" => Some(OutRule::Double),
+        "Triple" => Some(OutRule::Triple),
+        _ => None,
+    }
+}
+
+fn parse_bust_rule(value: &str) -> Option<BustRule> {
+    match value {
+        "Reset Turn" => Some(BustRule::ResetTurn),
+        "Reset To Start" => Some(BustRule::ResetToStart),
+        "No Bust" => Some(BustRule::NoBust),
+        _ => None,
+    }
+}
+
+fn escape_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+
+    for ch in name.chars() {
+        if ESCAPED_CHARS.contains(&ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+fn unescape_name(name: &str) -> String {
+    let mut unescaped = String::with_capacity(name.len());
+    let mut chars = name.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => unescaped.extend(chars.next()),
+            ch => unescaped.push(ch),
+        }
+    }
+
+    unescaped
+}
+
+/// Split the text at every separator that is not escaped with a backslash
+fn split_unescaped(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (index, ch) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == separator {
+            parts.push(&text[start..index]);
+            start = index + ch.len_utf8();
+        }
+    }
+
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Split a header line like `[Score "101"]` into its key and value
+fn parse_header(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.strip_prefix('[')?.strip_suffix(']')?.split_once(' ')?;
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+
+    Some((key, value))
+}
+
+fn parse_turn(turn: &str) -> Result<RecordedTurn, ParseError> {
+    let (name, throws) = match split_unescaped(turn, ':')[..] {
+        [name, throws] => (name, throws),
+        _ => return Err(ParseError::InvalidTurn(turn.into())),
+    };
+
+    let mut throws: Vec<&str> = throws.split_whitespace().collect();
+    let forfeited = throws.last() == Some(&FORFEIT_MARKER);
+
+    if forfeited {
+        throws.pop();
+    }
+
+    let throws = throws
+        .into_iter()
+        .map(|throw| Throw::from_str(throw.trim_end_matches(FINISH_MARKER)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ParseError::InvalidThrow)?;
+
+    Ok(RecordedTurn {
+        name: unescape_name(name.trim()),
+        throws,
+        forfeited,
+    })
+}
+
+impl Notation {
+    pub fn parse(text: &str) -> Result<Notation, ParseError> {
+        let mut score = None;
+        let mut max_score = DEFAULT_MAX_SCORE;
+        let mut in_rule = InRule::Any;
+        let mut out_rule = OutRule::Any;
+        let mut bust_rule = BustRule::default();
+        let mut builder = Participants::new();
+        let mut turns = vec![];
+
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if line.starts_with('[') {
+                let invalid = || ParseError::InvalidHeader(line.into());
+                let (key, value) = parse_header(line).ok_or_else(invalid)?;
+
+                match key {
+                    "Score" => score = Some(value.parse::<u32>().map_err(|_| invalid())?),
+                    "MaxScore" => {
+                        max_score = value
+                            .parse::<u32>()
+                            .ok()
+                            .and_then(|max_score| is_valid_score(max_score).ok())
+                            .ok_or_else(invalid)?
+                    }
+                    "In" => in_rule = parse_in_rule(value).ok_or_else(invalid)?,
+                    "Out" => out_rule = parse_out_rule(value).ok_or_else(invalid)?,
+                    "Bust" => bust_rule = parse_bust_rule(value).ok_or_else(invalid)?,
+                    "Player" => {
+                        let player = Player::new(&unescape_name(value))
+                            .map_err(ParseError::InvalidPlayer)?;
+                        builder = builder.add(&player)
+                    }
+                    "Team" => {
+                        let members = value
+                            .split(TEAM_SEPARATOR)
+                            .map(|name| Player::new(&unescape_name(name)))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(ParseError::InvalidPlayer)?;
+
+                        builder = builder.add_team(&members[0], &members[1..])
+                    }
+                    _ => return Err(invalid()),
+                }
+            } else {
+                let (_, round) = line
+                    .split_once(". ")
+                    .ok_or_else(|| ParseError::InvalidTurn(line.into()))?;
+
+                for turn in split_unescaped(round, '|') {
+                    turns.push(parse_turn(turn)?);
+                }
+            }
+        }
+
+        let score = score.ok_or(ParseError::InvalidScore)?;
+        is_score_within(score, max_score).map_err(|_| ParseError::InvalidScore)?;
+
+        let ruleset = Ruleset::new()
+            .score(score)
+            .map_err(|_| ParseError::InvalidScore)?
+            .max_score(max_score)
+            .expect("max score was validated")
+            .in_rule(in_rule)
+            .out_rule(out_rule)
+            .bust_rule(bust_rule)
+            .build();
+
+        let participants = builder.build().ok_or(ParseError::NoPlayers)?;
+
+        Ok(Notation {
+            ruleset,
+            participants,
+            turns,
+        })
+    }
+
+    /// Replay the recorded throws into a leg
+    pub fn leg(&self) -> Result<Leg<'_>, ParseError> {
+        let first_player = match self.turns.first() {
+            Some(RecordedTurn { name, .. }) => self
+                .participants
+                .participants
+                .iter()
+                .position(|participant| participant.player.name() == name)
+                .ok_or_else(|| ParseError::UnexpectedPlayer(name.clone()))?,
+            None => 0,
+        };
+
+        let mut leg = Leg::new(&self.ruleset, &self.participants, first_player);
+        let mut finished = false;
+
+        for (turn, recorded) in self.turns.iter().enumerate() {
+            let RecordedTurn {
+                name,
+                throws,
+                forfeited,
+            } = recorded;
+
+            if finished {
+                return Err(ParseError::ThrowsAfterFinish);
+            }
+
+            if leg.current_player().name() != name {
+                return Err(ParseError::UnexpectedPlayer(name.clone()));
+            }
+
+            for (dart, throw) in throws.iter().enumerate() {
+                if finished {
+                    return Err(ParseError::ThrowsAfterFinish);
+                }
+
+                // The turn already ended before all of its throws were used
+                if dart > 0 && leg.current_turn().num_throws() == 0 {
+                    return Err(ParseError::InvalidTurn(name.clone()));
+                }
+
+                let ThrowResult { state, leg: next } = leg
+                    .add_throw(throw.clone())
+                    .map_err(ParseError::InvalidLeg)?;
+                finished = state != State::Unfinished;
+                leg = next;
+            }
+
+            if *forfeited {
+                leg = leg.forfeit_turn().map_err(ParseError::InvalidLeg)?.leg;
+            }
+
+            let last_turn = turn + 1 == self.turns.len();
+
+            if !finished && !last_turn && leg.current_turn().num_throws() != 0 {
+                return Err(ParseError::InvalidTurn(name.clone()));
+            }
+        }
+
+        Ok(leg)
+    }
+}
+
+/// Write the notation of a leg, see [Notation] for the format
+pub(crate) fn write(leg: &Leg) -> String {
+    let ruleset = leg.ruleset();
+    let participants = leg.participants();
+
+    let mut text = format!(
+        "[Score \"{}\"]\n[In \"{}\"]\n[Out \"{}\"]\n[Bust \"{}\"]\n",
+        ruleset.score(),
+        ruleset.in_rule(),
+        ruleset.out_rule(),
+        ruleset.bust_rule()
+    );
+
+    if *ruleset.max_score() != DEFAULT_MAX_SCORE {
+        text += &format!("[MaxScore \"{}\"]\n", ruleset.max_score());
+    }
+
+    for participant in &participants.participants {
+        if participant.is_team() {
+            let members: Vec<_> = participant
+                .members()
+                .map(|player| escape_name(player.name()))
+                .collect();
+            text += &format!("[Team \"{}\"]\n", members.join(TEAM_SEPARATOR));
+        } else {
+            text += &format!("[Player \"{}\"]\n", escape_name(participant.player.name()));
+        }
+    }
+
+    text.push('\n');
+
+    let rounds = leg.rounds();
+    let last_round = rounds.len().saturating_sub(1);
+    let finished = leg.current_points() == 0;
+
+    for (round, round_turns) in rounds.iter().enumerate() {
+        let last_turn = round_turns.len() - 1;
+        let round_text = round_turns
+            .iter()
+            .enumerate()
+            .map(|(offset, (index, turn))| {
+                let mut throws: Vec<String> = turn.throws().iter().map(Throw::notation).collect();
+
+                if finished && round == last_round && offset == last_turn {
+                    if let Some(last) = throws.last_mut() {
+                        last.push(FINISH_MARKER);
+                    }
+                }
+
+                if turn.is_forfeited() {
+                    throws.push(FORFEIT_MARKER.into());
+                }
+
+                format!(
+                    "{}: {}",
+                    escape_name(participants[*index].thrower(round).name()),
+                    throws.join(" ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        text += &format!("{}. {}\n", round + 1, round_text);
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::x01::participants::{test_participants, test_teams};
+
+    use super::*;
+
+    fn play<'a>(leg: Leg<'a>, throws: &[&str]) -> Leg<'a> {
+        throws.iter().fold(leg, |leg, throw| {
+            leg.add_throw(Throw::from_str(throw).unwrap()).unwrap().leg
+        })
+    }
+
+    #[test]
+    fn finished_leg_is_written() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let leg = play(leg, &["T20", "1", "0", "T19", "T19", "D20"]);
+
+        assert_eq!(
+            leg.as_pgn_like_notation(),
+            "[Score \"101\"]\n[In \"Any\"]\n[Out \"Double\"]\n[Bust \"Reset Turn\"]\n\
+             [Player \"Anna\"]\n[Player \"Pete\"]\n\n\
+             1. Anna: T20 1 0 | Pete: T19 T19\n\
+             2. Anna: D20*\n"
+        );
+    }
+
+    #[test]
+    fn notation_round_trip_restores_leg() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 1);
+        let leg = play(leg, &["T20", "T20", "T20", "T19", "25", "D25", "5", "1"]);
+
+        let notation = Notation::parse(&leg.as_pgn_like_notation()).unwrap();
+
+        assert_eq!(notation.leg(), Ok(leg));
+    }
+
+    #[test]
+    fn notation_round_trip_keeps_bust_rule() {
+        let participants = test_participants(1);
+
+        for bust_rule in [
+            BustRule::ResetTurn,
+            BustRule::ResetToStart,
+            BustRule::NoBust,
+        ] {
+            let ruleset = Ruleset::new()
+                .score(101)
+                .unwrap()
+                .bust_rule(bust_rule.clone())
+                .build();
+
+            let leg = Leg::new(&ruleset, &participants, 0);
+            let leg = play(leg, &["T20", "1", "0", "T20", "1"]);
+
+            let text = leg.as_pgn_like_notation();
+            let notation = Notation::parse(&text).unwrap();
+
+            assert!(text.contains(&format!("[Bust \"{}\"]", bust_rule)));
+            assert_eq!(*notation.ruleset.bust_rule(), bust_rule);
+            assert_eq!(notation.leg(), Ok(leg));
+        }
+    }
+
+    #[test]
+    fn notation_round_trip_keeps_max_score() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .max_score(1001)
+            .unwrap()
+            .build();
+
+        let leg = play(Leg::new(&ruleset, &participants, 0), &["T20"]);
+        let text = leg.as_pgn_like_notation();
+        let notation = Notation::parse(&text).unwrap();
+
+        assert!(text.contains("[MaxScore \"1001\"]"));
+        assert_eq!(notation.ruleset, ruleset);
+        assert_eq!(
+            Notation::parse(&text.replace("[Score \"501\"]", "[Score \"2001\"]")),
+            Err(ParseError::InvalidScore)
+        );
+    }
+
+    #[test]
+    fn notation_round_trip_restores_team_leg() {
+        let participants = test_teams();
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let leg = play(leg, &["T20", "T20", "T20", "1", "1", "1", "T19", "T19"]);
+
+        let text = leg.as_pgn_like_notation();
+
+        assert!(text.contains("[Team \"Anna & Pete\"]"));
+        assert!(text.contains("2. Pete: T19 T19"));
+        assert_eq!(Notation::parse(&text).unwrap().leg(), Ok(leg));
+    }
+
+    #[test]
+    fn notation_round_trip_escapes_names() {
+        let player = |name| Player::new(name).unwrap();
+        let participants = Participants::new()
+            .add(&player("Dr. \"Dart\" O'Neil"))
+            .add_team(&player("Tom & Jerry"), &[player("A|B: C\\D")])
+            .build()
+            .unwrap();
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0);
+        let leg = play(leg, &["T20", "T20", "T20", "1", "1", "1", "T19", "T19"]);
+
+        let text = leg.as_pgn_like_notation();
+        let notation = Notation::parse(&text).unwrap();
+
+        assert!(text.contains("[Team \"Tom \\& Jerry & A\\|B\\: C\\\\D\"]"));
+        assert_eq!(notation.participants, participants);
+        assert_eq!(notation.leg(), Ok(leg));
+    }
+
+    #[test]
+    fn notation_round_trip_restores_forfeited_turns() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let leg = Leg::new(&ruleset, &participants, 0)
+            .forfeit_turn()
+            .unwrap()
+            .leg;
+        let leg = play(leg, &["T20"]).forfeit_turn().unwrap().leg;
+
+        let text = leg.as_pgn_like_notation();
+
+        assert!(text.contains("1. Anna: - | Pete: T20 -"));
+        assert_eq!(Notation::parse(&text).unwrap().leg(), Ok(leg));
+    }
+
+    #[test]
+    fn turn_of_wrong_player_is_rejected() {
+        let text =
+            "[Score \"101\"]\n[Player \"Anna\"]\n[Player \"Pete\"]\n\n1. Pete: 1 1 1 | Pete: 1\n";
+
+        let notation = Notation::parse(text).unwrap();
+
+        assert_eq!(
+            notation.leg(),
+            Err(ParseError::UnexpectedPlayer(String::from("Pete")))
+        );
+    }
+
+    #[test]
+    fn incomplete_turn_is_rejected() {
+        let text =
+            "[Score \"101\"]\n[Player \"Anna\"]\n[Player \"Pete\"]\n\n1. Anna: T20 | Pete: 1\n";
+
+        let notation = Notation::parse(text).unwrap();
+
+        assert_eq!(
+            notation.leg(),
+            Err(ParseError::InvalidTurn(String::from("Anna")))
+        );
+    }
+}
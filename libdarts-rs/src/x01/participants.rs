@@ -1,18 +1,54 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
 
 use crate::player::Player;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Participant {
     pub player: Player,
+    /// The other members if the participant is a team
+    pub teammates: Vec<Player>,
+    /// Retired participants keep their history but do not throw anymore
+    pub retired: bool,
 }
 
 impl Participant {
     pub fn new(player: &Player) -> Participant {
         Participant {
             player: player.clone(),
+            teammates: vec![],
+            retired: false,
         }
     }
+
+    /// Create a team whose members take turns throwing for a shared score
+    pub fn team(player: &Player, teammates: &[Player]) -> Participant {
+        Participant {
+            player: player.clone(),
+            teammates: teammates.to_vec(),
+            retired: false,
+        }
+    }
+
+    pub fn is_team(&self) -> bool {
+        !self.teammates.is_empty()
+    }
+
+    /// All members of the participant in throwing order
+    pub fn members(&self) -> impl Iterator<Item = &Player> {
+        std::iter::once(&self.player).chain(self.teammates.iter())
+    }
+
+    /// The member throwing the given turn (counted from 0) of the participant
+    pub fn thrower(&self, turn: usize) -> &Player {
+        let index = turn % (self.teammates.len() + 1);
+
+        self.members().nth(index).unwrap()
+    }
 }
 
 pub struct ParticipantsBuilder {
@@ -26,14 +62,37 @@ impl ParticipantsBuilder {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn add(mut self, player: &Player) -> Self {
-        self.participants.push(Participant {
-            player: player.clone(),
-        });
+        self.participants.push(Participant::new(player));
 
-        Self {
-            participants: self.participants,
-        }
+        self
+    }
+
+    /// Add all players of the slice in order
+    pub fn add_all(self, players: &[Player]) -> Self {
+        players
+            .iter()
+            .fold(self, |builder, player| builder.add(player))
+    }
+
+    /// Add all players of the iterator in order without cloning them
+    pub fn extend(mut self, players: impl IntoIterator<Item = Player>) -> Self {
+        self.participants
+            .extend(players.into_iter().map(|player| Participant {
+                player,
+                teammates: vec![],
+                retired: false,
+            }));
+
+        self
+    }
+
+    /// Add a team of players sharing one score
+    pub fn add_team(mut self, player: &Player, teammates: &[Player]) -> Self {
+        self.participants.push(Participant::team(player, teammates));
+
+        self
     }
 
     pub fn build(self) -> Option<Participants> {
@@ -62,7 +121,100 @@ pub fn test_participants(n: u8) -> Participants {
     participants.build().unwrap()
 }
 
+#[cfg(test)]
+pub fn test_teams() -> Participants {
+    let player = |name| Player::new(name).unwrap();
+
+    Participants::new()
+        .add_team(&player("Anna"), &[player("Pete")])
+        .add_team(&player("Bob"), &[player("Carl")])
+        .build()
+        .unwrap()
+}
+
+/// An error that occurs when the indices passed to [Participants::reorder] are no permutation
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReorderError {
+    /// The number of indices differs from the number of participants
+    WrongLength(usize),
+    /// The index appears more than once
+    DuplicateIndex(usize),
+    /// There is no participant with the index
+    IndexOutOfRange(usize),
+}
+
+impl Error for ReorderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for ReorderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReorderError::WrongLength(n) => {
+                writeln!(f, "Expected an index for each participant, got {}", n)
+            }
+            ReorderError::DuplicateIndex(index) => {
+                writeln!(f, "Index {} is used more than once", index)
+            }
+            ReorderError::IndexOutOfRange(index) => {
+                writeln!(f, "There is no participant {}", index)
+            }
+        }
+    }
+}
+
+/// An error that occurs when retiring a participant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetireError {
+    /// There is no participant with the index
+    IndexOutOfRange(usize),
+    AlreadyRetired(usize),
+    /// The participant is the last one still playing
+    LastActive(usize),
+}
+
+impl Error for RetireError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for RetireError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetireError::IndexOutOfRange(index) => {
+                writeln!(f, "There is no participant {}", index)
+            }
+            RetireError::AlreadyRetired(index) => {
+                writeln!(f, "Participant {} already retired", index)
+            }
+            RetireError::LastActive(index) => {
+                writeln!(f, "Participant {} is the last one playing", index)
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Participants {
     pub participants: Vec<Participant>,
 }
@@ -82,6 +234,7 @@ impl IndexMut<usize> for Participants {
 }
 
 impl Participants {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> ParticipantsBuilder {
         ParticipantsBuilder::new()
     }
@@ -89,4 +242,189 @@ impl Participants {
     pub fn count(&self) -> usize {
         self.participants.len()
     }
+
+    /// Mark a participant as retired, they are skipped in the turn order of new legs
+    ///
+    /// Use [Leg::retire] to retire a participant from a running leg.
+    ///
+    /// [Leg::retire]: super::leg::Leg::retire
+    pub fn retire(&mut self, index: usize) -> Result<(), RetireError> {
+        match self.participants.get(index) {
+            None => Err(RetireError::IndexOutOfRange(index)),
+            Some(participant) if participant.retired => Err(RetireError::AlreadyRetired(index)),
+            Some(_) if self.active_count() == 1 => Err(RetireError::LastActive(index)),
+            Some(_) => {
+                self.participants[index].retired = true;
+                Ok(())
+            }
+        }
+    }
+
+    /// Number of participants that did not retire
+    pub fn active_count(&self) -> usize {
+        self.participants.iter().filter(|p| !p.retired).count()
+    }
+
+    /// Create participants in a new order, `new_indices[i]` is the current index of the new `i`th participant
+    pub fn reorder(&self, new_indices: &[usize]) -> Result<Participants, ReorderError> {
+        if new_indices.len() != self.count() {
+            return Err(ReorderError::WrongLength(new_indices.len()));
+        }
+
+        let mut used = vec![false; self.count()];
+
+        for &index in new_indices {
+            match used.get_mut(index) {
+                None => return Err(ReorderError::IndexOutOfRange(index)),
+                Some(true) => return Err(ReorderError::DuplicateIndex(index)),
+                Some(slot) => *slot = true,
+            }
+        }
+
+        Ok(Participants {
+            participants: new_indices
+                .iter()
+                .map(|&index| self.participants[index].clone())
+                .collect(),
+        })
+    }
+
+    /// Exchange the participants at the two indices
+    ///
+    /// Panics if an index is out of range.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.participants.swap(i, j);
+    }
+
+    /// The participants in reverse order
+    pub fn reversed(&self) -> Participants {
+        Participants {
+            participants: self.participants.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// The first participant that did not retire, starting at `index` and wrapping around
+    pub fn next_active(&self, index: usize) -> Option<usize> {
+        let count = self.count();
+
+        (0..count)
+            .map(|offset| (index + offset) % count)
+            .find(|&index| !self.participants[index].retired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participants_are_swapped_and_reversed() {
+        let players: Vec<Player> = ["Anna", "Pete", "Tina"]
+            .iter()
+            .map(|name| Player::new(name).unwrap())
+            .collect();
+        let mut participants = Participants::new().add_all(&players).build().unwrap();
+
+        participants.swap(0, 2);
+        assert_eq!(participants[0].player, players[2]);
+        assert_eq!(participants[2].player, players[0]);
+
+        let reversed = participants.reversed();
+        assert_eq!(reversed[0].player, players[0]);
+        assert_eq!(reversed[1].player, players[1]);
+        assert_eq!(reversed[2].player, players[2]);
+    }
+
+    #[test]
+    fn team_members_take_turns() {
+        let participants = test_teams();
+        let team = &participants[0];
+
+        assert!(team.is_team());
+        assert_eq!(team.thrower(0).name(), "Anna");
+        assert_eq!(team.thrower(1).name(), "Pete");
+        assert_eq!(team.thrower(2).name(), "Anna");
+    }
+
+    #[test]
+    fn participants_are_built_from_slice() {
+        let players: Vec<_> = ["Anna", "Pete", "Bob"]
+            .iter()
+            .map(|name| Player::new(name).unwrap())
+            .collect();
+
+        let participants = Participants::new().add_all(&players).build().unwrap();
+
+        assert_eq!(participants.count(), 3);
+        assert_eq!(participants[2].player, players[2]);
+    }
+
+    #[test]
+    fn participants_are_built_from_iterator() {
+        let players = ["Anna", "Pete"]
+            .iter()
+            .map(|name| Player::new(name).unwrap());
+
+        let participants = Participants::new().extend(players).build().unwrap();
+
+        assert_eq!(participants, test_participants(2));
+    }
+
+    #[test]
+    fn retired_participants_are_not_active() {
+        let mut participants = test_participants(2);
+
+        participants.retire(0).unwrap();
+
+        assert_eq!(participants.active_count(), 1);
+        assert_eq!(participants.next_active(0), Some(1));
+        assert_eq!(participants.next_active(1), Some(1));
+    }
+
+    #[test]
+    fn retiring_is_validated() {
+        let mut participants = test_participants(2);
+
+        assert_eq!(participants.retire(2), Err(RetireError::IndexOutOfRange(2)));
+        participants.retire(0).unwrap();
+        assert_eq!(participants.retire(0), Err(RetireError::AlreadyRetired(0)));
+        assert_eq!(participants.retire(1), Err(RetireError::LastActive(1)));
+        assert_eq!(participants.active_count(), 1);
+    }
+
+    #[test]
+    fn participants_are_reordered() {
+        let participants = test_participants(2);
+
+        let reordered = participants.reorder(&[1, 0]).unwrap();
+
+        assert_eq!(reordered[0], participants[1]);
+        assert_eq!(reordered[1], participants[0]);
+    }
+
+    #[test]
+    fn invalid_permutations_are_rejected() {
+        let participants = test_participants(2);
+
+        assert_eq!(
+            participants.reorder(&[0]),
+            Err(ReorderError::WrongLength(1))
+        );
+        assert_eq!(
+            participants.reorder(&[1, 1]),
+            Err(ReorderError::DuplicateIndex(1))
+        );
+        assert_eq!(
+            participants.reorder(&[0, 2]),
+            Err(ReorderError::IndexOutOfRange(2))
+        );
+    }
+
+    #[test]
+    fn single_player_always_throws() {
+        let participants = test_participants(1);
+
+        assert!(!participants[0].is_team());
+        assert_eq!(participants[0].thrower(3).name(), "Anna");
+    }
 }
@@ -1,3 +1,8 @@
+// The builder and serde derives of SetOptions set the deprecated `num_sets` field
+#![allow(deprecated)]
+
+use std::{error::Error, fmt::Display};
+
 use builder_pattern::Builder;
 use getset::Getters;
 
@@ -12,15 +17,24 @@ fn is_positive(value: u8) -> Result<u8, ()> {
     }
 }
 
+#[cfg(feature = "serde")]
+fn default_num_sets() -> u8 {
+    1
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetOptions {
     /// The number of sets to play
+    #[deprecated(note = "the number of sets is given by `Ruleset::match_format`")]
     #[default(1)]
     #[validator(is_positive)]
+    #[cfg_attr(feature = "serde", serde(default = "default_num_sets"))]
     pub num_sets: u8,
 
-    /// The length of each set (number of legs)
-    // TODO: Sets are played best of x or first to x
+    /// The length of each set, a set is played best of this number of legs
+    ///
+    /// The number of sets is given by [Ruleset::match_format].
     #[default(1)]
     #[validator(is_positive)]
     pub num_legs: u8,
@@ -31,7 +45,29 @@ pub struct SetOptions {
     pub win_distance: u8,
 }
 
+impl Display for SetOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.num_legs == 1 {
+            write!(f, "1 leg")?;
+        } else {
+            write!(
+                f,
+                "Best of {} legs (first to {})",
+                self.num_legs,
+                self.num_legs / 2 + 1
+            )?;
+        }
+
+        if self.win_distance > 1 {
+            write!(f, ", won by {} legs", self.win_distance)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InRule {
     Any,
     Double,
@@ -48,7 +84,18 @@ impl InRule {
     }
 }
 
+impl Display for InRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InRule::Any => write!(f, "Any"),
+            InRule::Double => write!(f, "Double"),
+            InRule::Triple => write!(f, "Triple"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OutRule {
     Any,
     Double,
@@ -64,39 +111,351 @@ impl OutRule {
         }
     }
 
+    /// Check if the remaining points are enough to be finished with this out rule
     pub fn valid_remaining_points(&self, remaining_points: u32) -> bool {
+        remaining_points >= self.minimum_finishing_score()
+    }
+
+    /// Check if a throw that does not finish may leave the remaining points
+    ///
+    /// A remainder that cannot be finished anymore (e.g. 1 for double out) busts the turn.
+    /// Reaching 0 is a finish instead, which is checked with [Throw::is_achievable_with_out_rule].
+    pub fn can_leave(&self, remaining_points: u32) -> bool {
+        remaining_points != 0 && self.valid_remaining_points(remaining_points)
+    }
+
+    /// The lowest score that can be finished (e.g. 2 with D1 for double out)
+    pub fn minimum_finishing_score(&self) -> u32 {
+        match self {
+            OutRule::Any => 1,
+            OutRule::Double => 2,
+            OutRule::Triple => 3,
+        }
+    }
+
+    /// The highest score that can be finished in one turn (e.g. 170 with T20 T20 D25 for double out)
+    pub fn maximum_finishing_score(&self) -> u32 {
+        match self {
+            OutRule::Any | OutRule::Triple => 180,
+            OutRule::Double => 170,
+        }
+    }
+
+    /// Check if the remaining points can be finished with a single dart
+    pub fn one_dart_finish(&self, remaining_points: u32) -> bool {
+        let p = remaining_points;
         match self {
-            OutRule::Any => remaining_points >= 1,
-            OutRule::Double => remaining_points >= 2,
-            OutRule::Triple => remaining_points >= 3,
+            OutRule::Any => {
+                (1..=20).contains(&p)
+                    || ((2..=40).contains(&p) && p.is_multiple_of(2))
+                    || ((3..=60).contains(&p) && p.is_multiple_of(3))
+                    || p == 25
+                    || p == 50
+            }
+            OutRule::Double => ((2..=40).contains(&p) && p.is_multiple_of(2)) || p == 50,
+            OutRule::Triple => (3..=60).contains(&p) && p.is_multiple_of(3),
         }
     }
 }
 
-#[allow(dead_code)] // Needed because code is only used in macro Getters
-fn is_valid_score(score: u32) -> Result<u32, ()> {
-    if score > 1 && (score - 1) % 100 == 0 {
-        Ok(score)
+impl Display for OutRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutRule::Any => write!(f, "Any"),
+            OutRule::Double => write!(f, "Double"),
+            OutRule::Triple => write!(f, "Triple"),
+        }
+    }
+}
+
+/// What happens to the score of a player that busts
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BustRule {
+    /// The score is reset to the start of the busted turn
+    #[default]
+    ResetTurn,
+    /// The score is reset to the start score of the leg
+    ResetToStart,
+    /// Darts that would bust are recorded as a miss and the turn continues
+    NoBust,
+}
+
+impl Display for BustRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BustRule::ResetTurn => write!(f, "Reset Turn"),
+            BustRule::ResetToStart => write!(f, "Reset To Start"),
+            BustRule::NoBust => write!(f, "No Bust"),
+        }
+    }
+}
+
+/// How many sets a player has to win to take the match
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchFormat {
+    /// The first player to win the given number of sets wins the match
+    FirstTo(u8),
+    /// The match is played over at most the given number of sets, e.g. best of 5 is first to 3
+    BestOf(u8),
+}
+
+impl MatchFormat {
+    pub fn sets_to_win(&self) -> u8 {
+        match self {
+            MatchFormat::FirstTo(sets) => *sets,
+            MatchFormat::BestOf(sets) => sets / 2 + 1,
+        }
+    }
+
+    /// The number of sets the match is played over, `None` if it is not limited
+    pub fn max_sets(&self) -> Option<u8> {
+        match self {
+            MatchFormat::FirstTo(_) => None,
+            MatchFormat::BestOf(sets) => Some(*sets),
+        }
+    }
+}
+
+/// What happens if a match ends level after all sets were played
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OvertimeRule {
+    /// Sets are played until a player won enough of them
+    #[default]
+    None,
+    /// A single leg decides the match
+    SuddenDeathLeg,
+}
+
+impl Display for OvertimeRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OvertimeRule::None => write!(f, "None"),
+            OvertimeRule::SuddenDeathLeg => write!(f, "Sudden Death Leg"),
+        }
+    }
+}
+
+impl Default for MatchFormat {
+    fn default() -> Self {
+        MatchFormat::FirstTo(1)
+    }
+}
+
+impl Display for MatchFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchFormat::FirstTo(sets) => write!(f, "First to {} sets", sets),
+            MatchFormat::BestOf(sets) => write!(f, "Best of {} sets", sets),
+        }
+    }
+}
+
+/// The highest score a game can be started with, rulesets may lower it with [Ruleset::max_score]
+pub const DEFAULT_MAX_SCORE: u32 = 10001;
+
+#[cfg(feature = "serde")]
+fn default_max_score() -> u32 {
+    DEFAULT_MAX_SCORE
+}
+
+/// An error that occurs when setting the score of a [Ruleset]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InvalidScoreError {
+    /// Scores must be at least 2
+    TooSmall(u32),
+    /// Scores must not be larger than the maximum, carries the score and the maximum
+    TooLarge(u32, u32),
+}
+
+impl Error for InvalidScoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for InvalidScoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidScoreError::TooSmall(score) => writeln!(f, "{} is too small to play", score),
+            InvalidScoreError::TooLarge(score, max_score) => {
+                writeln!(f, "{} is larger than the maximum of {}", score, max_score)
+            }
+        }
+    }
+}
+
+/// Check if the score is one more than a multiple of 100, like 301 or 501
+///
+/// Rulesets accept any score from 2 up to [Ruleset::max_score] for short legs (e.g. 121 or 170).
+pub fn is_standard_x01_score(score: u32) -> bool {
+    score > 1 && (score - 1).is_multiple_of(100)
+}
+
+pub(crate) fn is_valid_score(score: u32) -> Result<u32, InvalidScoreError> {
+    is_score_within(score, DEFAULT_MAX_SCORE)
+}
+
+/// Check the score against a maximum, see [Ruleset::max_score]
+pub(crate) fn is_score_within(score: u32, max_score: u32) -> Result<u32, InvalidScoreError> {
+    if score > max_score {
+        Err(InvalidScoreError::TooLarge(score, max_score))
+    } else if score < 2 {
+        Err(InvalidScoreError::TooSmall(score))
     } else {
-        Err(())
+        Ok(score)
+    }
+}
+
+/// A violation found by [Ruleset::validate]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RulesetValidationError {
+    /// The start score is not valid
+    InvalidScore(InvalidScoreError),
+    /// The start score is lower than the lowest score the out rule can finish
+    UnreachableFinish(u32, OutRule),
+    /// The number of legs, the win distance or the sets of the match format is zero
+    InvalidSetOptions,
+}
+
+impl Error for RulesetValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for RulesetValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesetValidationError::InvalidScore(err) => write!(f, "{}", err),
+            RulesetValidationError::UnreachableFinish(score, out_rule) => {
+                writeln!(f, "{} cannot be finished with {} out", score, out_rule)
+            }
+            RulesetValidationError::InvalidSetOptions => {
+                writeln!(
+                    f,
+                    "Legs, win distance and sets of the match must be positive"
+                )
+            }
+        }
     }
 }
 
 #[derive(Builder, Debug, Clone, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[get = "pub"]
 pub struct Ruleset {
     #[validator(is_valid_score)]
     #[public]
     score: u32,
+    /// The highest start score the ruleset accepts, at most [DEFAULT_MAX_SCORE]
+    #[default(DEFAULT_MAX_SCORE)]
+    #[validator(is_valid_score)]
+    #[public]
+    #[cfg_attr(feature = "serde", serde(default = "default_max_score"))]
+    max_score: u32,
     #[default(InRule::Any)]
     #[public]
     in_rule: InRule,
     #[default(OutRule::Any)]
     #[public]
     out_rule: OutRule,
+    #[default(BustRule::ResetTurn)]
+    #[public]
+    #[cfg_attr(feature = "serde", serde(default))]
+    bust_rule: BustRule,
     #[default(SetOptions::new().build())]
     #[public]
     sets: SetOptions,
+    #[default(MatchFormat::FirstTo(1))]
+    #[public]
+    #[cfg_attr(feature = "serde", serde(default))]
+    match_format: MatchFormat,
+    #[default(OvertimeRule::None)]
+    #[public]
+    #[cfg_attr(feature = "serde", serde(default))]
+    overtime_rule: OvertimeRule,
+}
+
+impl Ruleset {
+    /// Copy the ruleset with another start score, validated the same way as by the builder
+    pub fn clone_with_score(&self, new_score: u32) -> Result<Ruleset, InvalidScoreError> {
+        Ok(Ruleset {
+            score: is_score_within(new_score, self.max_score)?,
+            ..self.clone()
+        })
+    }
+
+    /// Check if both rulesets have the same score, in rule and out rule
+    ///
+    /// Unlike `==` the bust rule and the format of sets and the match are ignored.
+    pub fn same_scoring(&self, other: &Ruleset) -> bool {
+        self.score == other.score
+            && self.in_rule == other.in_rule
+            && self.out_rule == other.out_rule
+    }
+
+    /// Check the ruleset as a whole and report every violation found
+    ///
+    /// The builder validates each field, but rulesets created in other ways
+    /// (e.g. deserialized ones) skip these checks.
+    pub fn validate(&self) -> Result<(), Vec<RulesetValidationError>> {
+        let mut errors = vec![];
+
+        if let Err(err) = is_score_within(self.score, self.max_score) {
+            errors.push(RulesetValidationError::InvalidScore(err));
+        } else if self.score < self.out_rule.minimum_finishing_score() {
+            errors.push(RulesetValidationError::UnreachableFinish(
+                self.score,
+                self.out_rule.clone(),
+            ));
+        }
+
+        let sets = &self.sets;
+        if sets.num_legs == 0
+            || sets.win_distance == 0
+            || matches!(
+                self.match_format,
+                MatchFormat::FirstTo(0) | MatchFormat::BestOf(0)
+            )
+        {
+            errors.push(RulesetValidationError::InvalidSetOptions);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Display for Ruleset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} In / {} Out",
+            self.score, self.in_rule, self.out_rule
+        )
+    }
 }
 
 #[cfg(test)]
@@ -116,9 +475,135 @@ mod tests {
     }
 
     #[test]
-    fn game_with_invalid_score_fails() {
-        let game = Ruleset::new().score(100);
-        assert!(game.is_err());
+    fn built_ruleset_is_valid() {
+        let ruleset = Ruleset::new().score(501).unwrap().build();
+
+        assert_eq!(ruleset.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validation_reports_all_violations() {
+        let mut ruleset = Ruleset::new().score(501).unwrap().build();
+        ruleset.score = 0;
+        ruleset.sets.num_legs = 0;
+
+        assert_eq!(
+            ruleset.validate(),
+            Err(vec![
+                RulesetValidationError::InvalidScore(InvalidScoreError::TooSmall(0)),
+                RulesetValidationError::InvalidSetOptions
+            ])
+        );
+    }
+
+    #[test]
+    fn finishing_score_range_depends_on_out_rule() {
+        let ranges: Vec<_> = [OutRule::Any, OutRule::Double, OutRule::Triple]
+            .iter()
+            .map(|rule| {
+                (
+                    rule.minimum_finishing_score(),
+                    rule.maximum_finishing_score(),
+                )
+            })
+            .collect();
+
+        assert_eq!(ranges, [(1, 180), (2, 170), (3, 180)]);
+    }
+
+    #[test]
+    fn ruleset_is_cloned_with_other_score() {
+        let ruleset = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+
+        let short = ruleset.clone_with_score(301).unwrap();
+
+        assert_eq!(*short.score(), 301);
+        assert_eq!(short.out_rule(), ruleset.out_rule());
+        assert_eq!(
+            ruleset.clone_with_score(1),
+            Err(InvalidScoreError::TooSmall(1))
+        );
+    }
+
+    #[test]
+    fn short_leg_scores_are_valid() {
+        for score in [2, 51, 100, 121, 170] {
+            assert!(Ruleset::new().score(score).is_ok());
+            assert!(!is_standard_x01_score(score));
+        }
+
+        assert!(is_standard_x01_score(501));
+    }
+
+    #[test]
+    fn score_below_finish_of_out_rule_is_invalid() {
+        let ruleset = Ruleset::new()
+            .score(2)
+            .unwrap()
+            .out_rule(OutRule::Triple)
+            .build();
+
+        assert_eq!(
+            ruleset.validate(),
+            Err(vec![RulesetValidationError::UnreachableFinish(
+                2,
+                OutRule::Triple
+            )])
+        );
+        assert_eq!(
+            RulesetValidationError::UnreachableFinish(2, OutRule::Triple).to_string(),
+            "2 cannot be finished with Triple out\n"
+        );
+        assert_eq!(
+            RulesetValidationError::UnreachableFinish(1, OutRule::Double).to_string(),
+            "1 cannot be finished with Double out\n"
+        );
+    }
+
+    #[test]
+    fn scores_up_to_maximum_are_valid() {
+        assert_eq!(is_valid_score(501), Ok(501));
+        assert_eq!(is_valid_score(1001), Ok(1001));
+        assert_eq!(is_valid_score(DEFAULT_MAX_SCORE), Ok(DEFAULT_MAX_SCORE));
+    }
+
+    #[test]
+    fn scores_above_maximum_are_rejected() {
+        assert_eq!(
+            is_valid_score(100001),
+            Err(InvalidScoreError::TooLarge(100001, DEFAULT_MAX_SCORE))
+        );
+        assert!(Ruleset::new().score(100001).is_err());
+    }
+
+    #[test]
+    fn maximum_score_is_configurable() {
+        let ruleset = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .max_score(1001)
+            .unwrap()
+            .build();
+
+        assert_eq!(ruleset.validate(), Ok(()));
+        assert_eq!(
+            ruleset.clone_with_score(2001),
+            Err(InvalidScoreError::TooLarge(2001, 1001))
+        );
+
+        let mut too_large = ruleset.clone();
+        too_large.score = 2001;
+        assert_eq!(
+            too_large.validate(),
+            Err(vec![RulesetValidationError::InvalidScore(
+                InvalidScoreError::TooLarge(2001, 1001)
+            )])
+        );
+        assert!(Ruleset::new().max_score(100001).is_err());
     }
 
     #[test]
@@ -127,12 +612,98 @@ mod tests {
         assert!(game.is_err());
     }
 
+    #[test]
+    fn only_double_bull_is_a_double_out_finisher() {
+        assert!(OutRule::Double.valid_finisher(&Throw::double_bull().unwrap()));
+        assert!(!OutRule::Double.valid_finisher(&Throw::single_bull().unwrap()));
+    }
+
+    #[test]
+    fn double_out_cannot_leave_one() {
+        assert!(!OutRule::Double.can_leave(1));
+        assert!(OutRule::Double.can_leave(2));
+        assert!(!OutRule::Double.can_leave(0));
+    }
+
+    #[test]
+    fn remainders_that_can_be_left_depend_on_out_rule() {
+        assert!(OutRule::Any.can_leave(1));
+        assert!(!OutRule::Triple.can_leave(2));
+        assert!(OutRule::Triple.can_leave(3));
+    }
+
+    #[test]
+    fn one_dart_finishes_respect_out_rule() {
+        assert!(OutRule::Double.one_dart_finish(40));
+        assert!(OutRule::Double.one_dart_finish(50));
+        assert!(!OutRule::Double.one_dart_finish(41));
+        assert!(!OutRule::Double.one_dart_finish(25));
+        assert!(OutRule::Any.one_dart_finish(25));
+        assert!(OutRule::Triple.one_dart_finish(60));
+        assert!(!OutRule::Triple.one_dart_finish(50));
+    }
+
+    #[test]
+    fn rules_are_displayed() {
+        assert_eq!(InRule::Any.to_string(), "Any");
+        assert_eq!(InRule::Double.to_string(), "Double");
+        assert_eq!(OutRule::Triple.to_string(), "Triple");
+    }
+
+    #[test]
+    fn set_options_are_displayed() {
+        let single = SetOptions::new().build();
+        assert_eq!(single.to_string(), "1 leg");
+
+        let best_of_7 = SetOptions::new().num_legs(7).unwrap().build();
+        assert_eq!(best_of_7.to_string(), "Best of 7 legs (first to 4)");
+
+        let sets = SetOptions::new()
+            .num_legs(5)
+            .unwrap()
+            .win_distance(2)
+            .unwrap()
+            .build();
+        assert_eq!(
+            sets.to_string(),
+            "Best of 5 legs (first to 3), won by 2 legs"
+        );
+    }
+
+    #[test]
+    fn ruleset_is_displayed() {
+        let ruleset = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .in_rule(InRule::Double)
+            .out_rule(OutRule::Double)
+            .build();
+
+        assert_eq!(ruleset.to_string(), "501 Double In / Double Out");
+    }
+
     #[test]
     fn games_with_0_sets_are_not_possible() {
         let set_options = SetOptions::new().num_sets(0);
         assert!(set_options.is_err());
     }
 
+    #[test]
+    fn match_formats_without_sets_are_not_possible() {
+        for match_format in [MatchFormat::FirstTo(0), MatchFormat::BestOf(0)] {
+            let ruleset = Ruleset::new()
+                .score(501)
+                .unwrap()
+                .match_format(match_format)
+                .build();
+
+            assert_eq!(
+                ruleset.validate(),
+                Err(vec![RulesetValidationError::InvalidSetOptions])
+            );
+        }
+    }
+
     #[test]
     fn games_with_0_lets_are_not_possible() {
         let set_options = SetOptions::new().num_legs(0);
@@ -143,4 +714,34 @@ mod tests {
         let set_options = SetOptions::new().win_distance(0);
         assert!(set_options.is_err());
     }
+
+    #[test]
+    fn set_options_do_not_change_scoring() {
+        let single_leg = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .build();
+        let best_of_5 = Ruleset::new()
+            .score(501)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .sets(SetOptions::new().num_legs(5).unwrap().build())
+            .build();
+
+        assert!(single_leg.same_scoring(&best_of_5));
+        assert_ne!(single_leg, best_of_5);
+        assert!(!single_leg.same_scoring(&single_leg.clone_with_score(301).unwrap()));
+    }
+
+    #[test]
+    fn match_format_defines_sets_to_win() {
+        assert_eq!(MatchFormat::FirstTo(3).sets_to_win(), 3);
+        assert_eq!(MatchFormat::BestOf(5).sets_to_win(), 3);
+        assert_eq!(MatchFormat::BestOf(7).sets_to_win(), 4);
+        assert_eq!(
+            Ruleset::new().score(501).unwrap().build().match_format(),
+            &MatchFormat::FirstTo(1)
+        );
+    }
 }
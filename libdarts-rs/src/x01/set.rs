@@ -3,18 +3,21 @@ use std::{error::Error, fmt::Display};
 use crate::throw::Throw;
 
 use super::{
-    leg::{self, Leg, ThrowResult},
+    leg::{self, Leg, LegError, ThrowResult},
     participants::Participants,
     ruleset::Ruleset,
 };
 
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Set<'a> {
     ruleset: &'a Ruleset,
     participants: &'a Participants,
     legs: Vec<Leg<'a>>,
     current_leg: Leg<'a>,
     first_player: usize,
+    /// Legs won per participant before the set was resumed, see [Set::resume]
+    resumed_legs_won: Vec<usize>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -46,6 +49,200 @@ impl Display for CreateSetError {
     }
 }
 
+/// An error that occurs when resuming a set with [Set::resume]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ResumeError {
+    /// The number of leg tallies does not match the number of participants
+    WrongTallyLength(usize),
+    InvalidFirstPlayer(usize),
+    /// Replaying the throws of the current leg failed
+    InvalidLeg(LegError),
+}
+
+impl Error for ResumeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+impl Display for ResumeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResumeError::WrongTallyLength(n) => {
+                writeln!(f, "Got {} leg tallies for the participants", n)
+            }
+            ResumeError::InvalidFirstPlayer(n) => {
+                writeln!(f, "First player '{}' is invalid.", n)
+            }
+            ResumeError::InvalidLeg(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Owned form of a set as exported by [Set::to_json]
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct SetDocument {
+    pub ruleset: Ruleset,
+    pub participants: Participants,
+    legs: Vec<leg::LegCheckpoint>,
+    current_leg: leg::LegCheckpoint,
+    first_player: usize,
+    #[serde(default)]
+    resumed_legs_won: Vec<usize>,
+}
+
+/// An error that occurs when rebuilding a set with [SetDocument::set]
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum SetDocumentError {
+    InvalidRuleset(Vec<super::ruleset::RulesetValidationError>),
+    NoParticipants,
+    InvalidFirstPlayer(usize),
+    /// The number of resumed leg tallies does not match the number of participants
+    WrongTallyLength(usize),
+    InvalidLeg(leg::RestoreLegError),
+    /// The finished leg with the index has no winner
+    UnfinishedLeg(usize),
+}
+
+#[cfg(feature = "serde")]
+impl Error for SetDocumentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+
+    fn description(&self) -> &str {
+        "description() is deprecated; use Display"
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        self.source()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Display for SetDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetDocumentError::InvalidRuleset(errors) => {
+                for err in errors {
+                    write!(f, "{}", err)?;
+                }
+                Ok(())
+            }
+            SetDocumentError::NoParticipants => writeln!(f, "The set has no participants"),
+            SetDocumentError::InvalidFirstPlayer(n) => {
+                writeln!(f, "First player '{}' is invalid.", n)
+            }
+            SetDocumentError::WrongTallyLength(n) => {
+                writeln!(f, "Got {} leg tallies for the participants", n)
+            }
+            SetDocumentError::InvalidLeg(err) => write!(f, "{}", err),
+            SetDocumentError::UnfinishedLeg(n) => writeln!(f, "Leg {} was not finished", n),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl SetDocument {
+    /// Parse a document created by [Set::to_json]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Rebuild the set described by the document
+    ///
+    /// The document is validated like a set created with [Set::new], because a parsed
+    /// document skips the checks of the builders.
+    pub fn set(&self) -> Result<Set<'_>, SetDocumentError> {
+        let ruleset = &self.ruleset;
+        let participants = &self.participants;
+
+        ruleset
+            .validate()
+            .map_err(SetDocumentError::InvalidRuleset)?;
+
+        if participants.count() == 0 {
+            return Err(SetDocumentError::NoParticipants);
+        }
+
+        self.set_with(ruleset, participants)
+    }
+
+    /// Rebuild the set with the already validated ruleset and participants it was played with
+    pub(crate) fn set_with<'a>(
+        &self,
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+    ) -> Result<Set<'a>, SetDocumentError> {
+        if self.first_player >= participants.count() {
+            return Err(SetDocumentError::InvalidFirstPlayer(self.first_player));
+        }
+
+        if !self.resumed_legs_won.is_empty() && self.resumed_legs_won.len() != participants.count()
+        {
+            return Err(SetDocumentError::WrongTallyLength(
+                self.resumed_legs_won.len(),
+            ));
+        }
+
+        let restore = |checkpoint: &leg::LegCheckpoint| {
+            Leg::restore(ruleset, participants, checkpoint.clone())
+                .map_err(SetDocumentError::InvalidLeg)
+        };
+
+        let legs = self
+            .legs
+            .iter()
+            .map(restore)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(index) = legs.iter().position(|leg| leg.winner().is_none()) {
+            return Err(SetDocumentError::UnfinishedLeg(index));
+        }
+
+        Ok(Set {
+            ruleset,
+            participants,
+            legs,
+            current_leg: restore(&self.current_leg)?,
+            first_player: self.first_player,
+            resumed_legs_won: self.resumed_legs_won.clone(),
+        })
+    }
+}
+
+/// Sum the checkout attempts and successful checkouts of a player over the legs
+pub(crate) fn checkout_tally<'l, 'a: 'l>(
+    legs: impl IntoIterator<Item = &'l Leg<'a>>,
+    player_index: usize,
+) -> (usize, usize) {
+    legs.into_iter().fold((0, 0), |(attempts, checkouts), leg| {
+        (
+            attempts + leg.checkout_attempts(player_index),
+            checkouts + leg.checkouts(player_index),
+        )
+    })
+}
+
+/// Percentage of the attempts that were converted, 0 without attempts
+pub(crate) fn checkout_rate(attempts: usize, checkouts: usize) -> f64 {
+    if attempts == 0 {
+        0.0
+    } else {
+        checkouts as f64 / attempts as f64 * 100.0
+    }
+}
+
 impl<'a> Set<'a> {
     pub fn new(
         ruleset: &'a Ruleset,
@@ -61,26 +258,241 @@ impl<'a> Set<'a> {
                 legs: vec![],
                 current_leg: Leg::new(ruleset, participants, first_player),
                 first_player,
+                resumed_legs_won: vec![],
             })
         }
     }
 
+    /// Continue a saved set from the legs won by each participant
+    ///
+    /// The throws of the current leg are replayed into a leg started by `first_player`.
+    /// Sets resumed this way only know the tally of the earlier legs, not their throws.
+    pub fn resume(
+        ruleset: &'a Ruleset,
+        participants: &'a Participants,
+        legs_won: Vec<u8>,
+        current_leg_throws: Vec<Throw>,
+        first_player: usize,
+    ) -> Result<Self, ResumeError> {
+        if legs_won.len() != participants.count() {
+            return Err(ResumeError::WrongTallyLength(legs_won.len()));
+        }
+
+        let mut set = Set::new(ruleset, participants, first_player).map_err(|err| match err {
+            CreateSetError::InvalidFirstPlayer(n) => ResumeError::InvalidFirstPlayer(n),
+        })?;
+        set.resumed_legs_won = legs_won.into_iter().map(usize::from).collect();
+
+        for throw in current_leg_throws {
+            set = set.add_throw(throw).map_err(ResumeError::InvalidLeg)?;
+        }
+
+        Ok(set)
+    }
+
+    /// Number of finished legs, including those before the set was resumed
+    fn finished_legs(&self) -> usize {
+        self.legs.len() + self.resumed_legs_won.iter().sum::<usize>()
+    }
+
     pub fn current_leg_number(&self) -> usize {
-        self.legs.len() + 1
+        self.finished_legs() + 1
+    }
+
+    pub fn current_leg(&self) -> &Leg<'a> {
+        &self.current_leg
+    }
+
+    /// The finished legs of the set in the order they were played
+    ///
+    /// Legs from before the set was resumed are not included.
+    pub fn completed_legs(&self) -> &[Leg<'a>] {
+        &self.legs
+    }
+
+    /// Number of legs played so far, including the current one
+    pub fn leg_count(&self) -> usize {
+        self.finished_legs() + 1
+    }
+
+    /// Export the complete set as JSON.
+    ///
+    /// The document contains the `ruleset`, the `participants`, the index of the
+    /// `first_player` of the current leg, the finished `legs` and the `current_leg`.
+    /// Each leg holds the `current` player (`index`, `points` at the start of the turn
+    /// and the `turn` in progress) and the committed `turns` of every participant in `data`.
+    /// A turn is a list of `throws` plus its `bust` flag. Sets created with [Set::resume]
+    /// also hold the `resumed_legs_won` per participant. Use [SetDocument::from_json] to
+    /// read it back.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("sets can always be serialized")
     }
 
-    pub fn add_throw(mut self, throw: Throw) -> Self {
-        let ThrowResult { state, leg } = self.current_leg.add_throw(throw);
+    /// Percentage of checkout attempts a player converted across all legs of the set
+    pub fn checkout_rate(&self, player_index: usize) -> f64 {
+        let (attempts, checkouts) = self.checkout_tally(player_index);
+
+        checkout_rate(attempts, checkouts)
+    }
+
+    /// Checkout attempts and successful checkouts of a player across all legs of the set
+    pub(crate) fn checkout_tally(&self, player_index: usize) -> (usize, usize) {
+        checkout_tally(
+            self.legs.iter().chain(std::iter::once(&self.current_leg)),
+            player_index,
+        )
+    }
+
+    /// Fraction of the finished legs that were won by the player who started the leg
+    pub fn starting_player_win_rate(&self) -> f64 {
+        if self.legs.is_empty() {
+            return 0.0;
+        }
+
+        let held = self
+            .legs
+            .iter()
+            .filter(|leg| leg.winner() == Some(leg.first_player()))
+            .count();
+
+        held as f64 / self.legs.len() as f64
+    }
+
+    /// Number of finished legs the player won
+    pub fn legs_won(&self, player_index: usize) -> usize {
+        let resumed = self
+            .resumed_legs_won
+            .get(player_index)
+            .copied()
+            .unwrap_or_default();
+
+        resumed
+            + self
+                .legs
+                .iter()
+                .filter(|leg| leg.winner() == Some(player_index))
+                .count()
+    }
+
+    /// Number of legs won by each participant
+    fn legs_won_by_all(&self) -> Vec<usize> {
+        (0..self.participants.count())
+            .map(|player| self.legs_won(player))
+            .collect()
+    }
 
-        self.current_leg = match state {
-            leg::State::Finished => {
-                // TODO: Check if set is finished!
-                self.first_player = (self.first_player + 1) % self.participants.count();
-                self.legs.push(leg);
-                Leg::new(self.ruleset, self.participants, self.first_player)
+    /// The player that has won a set with the given number of legs won per player
+    fn winner_by_legs(&self, won: &[usize]) -> Option<usize> {
+        let options = self.ruleset.sets();
+        let first_to = usize::from(options.num_legs / 2 + 1);
+        let win_distance = usize::from(options.win_distance);
+
+        (0..won.len()).find(|&player| {
+            won[player] >= first_to
+                && won
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != player)
+                    .all(|(_, &legs)| won[player] >= legs + win_distance)
+        })
+    }
+
+    /// The player that won the set
+    ///
+    /// A player needs to win the majority of the legs and lead every other player
+    /// by at least `win_distance` legs, so a tied set continues until someone pulls ahead.
+    pub fn set_winner(&self) -> Option<usize> {
+        self.winner_by_legs(&self.legs_won_by_all())
+    }
+
+    /// Check if the current leg decides the set
+    ///
+    /// This is the case if the set is level and more than one player would win the set
+    /// by winning the current leg.
+    pub fn is_decider(&self) -> bool {
+        let won = self.legs_won_by_all();
+
+        if self.winner_by_legs(&won).is_some() {
+            return false;
+        }
+
+        let deciding_players = (0..won.len())
+            .filter(|&player| {
+                let mut won = won.clone();
+                won[player] += 1;
+                self.winner_by_legs(&won) == Some(player)
+            })
+            .count();
+
+        deciding_players > 1
+    }
+
+    /// Add a throw to the current leg and start the next leg once it is finished
+    ///
+    /// Fails once the set is won.
+    pub fn add_throw(self, throw: Throw) -> Result<Self, LegError> {
+        if self.set_winner().is_some() {
+            return Err(LegError::ThrowsAfterFinish(1));
+        }
+
+        let ThrowResult { state, leg } = self.current_leg.add_throw(throw)?;
+
+        Ok(Set {
+            current_leg: leg,
+            ..self
+        }
+        .continue_after(state))
+    }
+
+    /// Retire a participant from the current leg and all following legs of the set
+    ///
+    /// See [Leg::retire], the last participant left wins the remaining legs.
+    pub fn retire(self, player_index: usize) -> Result<Self, LegError> {
+        let ThrowResult { state, leg } = self.current_leg.retire(player_index)?;
+
+        Ok(Set {
+            current_leg: leg,
+            ..self
+        }
+        .continue_after(state))
+    }
+
+    /// Start the set following this one, participants that retired stay retired
+    pub(crate) fn next_set(&self, first_player: usize) -> Set<'a> {
+        Set {
+            ruleset: self.ruleset,
+            participants: self.participants,
+            legs: vec![],
+            current_leg: self.current_leg.next_leg(first_player),
+            first_player,
+            resumed_legs_won: vec![],
+        }
+        .settle()
+    }
+
+    fn continue_after(mut self, state: leg::State) -> Self {
+        match state {
+            leg::State::Finished { .. } => {
+                self.start_next_leg();
+                self.settle()
             }
-            leg::State::Unfinished => leg,
-        };
+            leg::State::Unfinished => self,
+        }
+    }
+
+    fn start_next_leg(&mut self) {
+        self.first_player = (self.first_player + 1) % self.participants.count();
+        let next_leg = self.current_leg.next_leg(self.first_player);
+        self.legs
+            .push(std::mem::replace(&mut self.current_leg, next_leg));
+    }
+
+    /// Award the legs to the last participant left until the set is won
+    fn settle(mut self) -> Self {
+        while self.set_winner().is_none() && self.current_leg.winner().is_some() {
+            self.start_next_leg();
+        }
 
         self
     }
@@ -88,7 +500,10 @@ impl<'a> Set<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::x01::{participants::test_participants, ruleset::Ruleset};
+    use crate::x01::{
+        participants::test_participants,
+        ruleset::{OutRule, Ruleset, SetOptions},
+    };
 
     use super::*;
 
@@ -101,4 +516,279 @@ mod tests {
 
         assert_eq!(set, Err(CreateSetError::InvalidFirstPlayer(2)));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_restores_set() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+
+        for throw in ["T20", "D20", "1", "T20"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.current_leg_number(), 2);
+
+        let document = SetDocument::from_json(&set.to_json()).unwrap();
+
+        assert_eq!(document.set(), Ok(set));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_set_documents_are_rejected() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        set = set.add_throw(Throw::triple(20).unwrap()).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&set.to_json()).unwrap();
+        let rebuild = |change: &dyn Fn(&mut serde_json::Value)| {
+            let mut json = json.clone();
+            change(&mut json);
+            SetDocument::from_json(&json.to_string())
+                .unwrap()
+                .set()
+                .map(|_| ())
+        };
+
+        assert!(matches!(
+            rebuild(&|json| json["ruleset"]["score"] = 0.into()),
+            Err(SetDocumentError::InvalidRuleset(_))
+        ));
+        assert_eq!(
+            rebuild(&|json| json["first_player"] = 2.into()),
+            Err(SetDocumentError::InvalidFirstPlayer(2))
+        );
+        assert_eq!(
+            rebuild(&|json| json["resumed_legs_won"] = vec![1].into()),
+            Err(SetDocumentError::WrongTallyLength(1))
+        );
+        assert_eq!(
+            rebuild(&|json| {
+                json["current_leg"]["data"].as_array_mut().unwrap().pop();
+            }),
+            Err(SetDocumentError::InvalidLeg(
+                leg::RestoreLegError::WrongParticipantCount(1)
+            ))
+        );
+        assert_eq!(
+            rebuild(&|json| json["legs"] = vec![json["current_leg"].clone()].into()),
+            Err(SetDocumentError::UnfinishedLeg(0))
+        );
+    }
+
+    #[test]
+    fn checkout_rate_is_aggregated_over_legs() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .out_rule(OutRule::Double)
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+
+        assert_eq!(set.checkout_rate(0), 0.0);
+
+        for throw in ["T20", "1", "D20", "T20", "1", "0", "0"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.current_leg_number(), 2);
+        assert!((set.checkout_rate(0) - 100.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn throws_after_set_is_won_are_rejected() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new().score(101).unwrap().build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        for throw in ["T20", "D20", "1"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.set_winner(), Some(0));
+        assert_eq!(
+            set.add_throw(Throw::triple(20).unwrap()),
+            Err(LegError::ThrowsAfterFinish(1))
+        );
+    }
+
+    #[test]
+    fn last_player_left_wins_the_set() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        for throw in ["T20", "D20", "1", "T20"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        let set = set.retire(0).unwrap();
+
+        assert_eq!(set.set_winner(), Some(1));
+        assert_eq!((set.legs_won(0), set.legs_won(1)), (1, 2));
+        assert!(set.current_leg().is_retired(0));
+    }
+
+    #[test]
+    fn set_is_won_by_win_distance() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(
+                SetOptions::new()
+                    .num_legs(3)
+                    .unwrap()
+                    .win_distance(2)
+                    .unwrap()
+                    .build(),
+            )
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+
+        // The starting player wins each leg, so the set is tied at 2-2
+        for _ in 0..4 {
+            for throw in ["T20", "D20", "1"] {
+                set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+            }
+        }
+
+        assert_eq!((set.legs_won(0), set.legs_won(1)), (2, 2));
+        assert_eq!(set.set_winner(), None);
+
+        // Anna holds her throw and breaks the one of Pete
+        for throw in ["T20", "D20", "1", "0", "0", "0", "T20", "D20", "1"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!((set.legs_won(0), set.legs_won(1)), (4, 2));
+        assert_eq!(set.set_winner(), Some(0));
+    }
+
+    #[test]
+    fn set_is_resumed_from_tally() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .build();
+
+        let throws = ["T20", "D20", "0", "T19"]
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect();
+        let set = Set::resume(&ruleset, &participants, vec![1, 0], throws, 1).unwrap();
+
+        assert_eq!(set.current_leg_number(), 2);
+        assert_eq!((set.legs_won(0), set.legs_won(1)), (1, 0));
+        assert_eq!(set.current_leg().first_player(), 1);
+        assert_eq!(set.current_leg().current_points(), 44);
+
+        // Anna finishes her turn on 43 and Pete checks out his last point
+        let mut set = set;
+        for throw in ["1", "0", "1"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+        assert_eq!((set.legs_won(0), set.legs_won(1)), (1, 1));
+        assert!(set.is_decider());
+
+        assert_eq!(
+            Set::resume(&ruleset, &participants, vec![1], vec![], 0),
+            Err(ResumeError::WrongTallyLength(1))
+        );
+    }
+
+    #[test]
+    fn finished_legs_are_kept_in_order() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(5).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        assert_eq!(set.leg_count(), 1);
+        assert!(set.completed_legs().is_empty());
+
+        for throw in ["T20", "D20", "1", "T20"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.leg_count(), 2);
+        assert_eq!(set.completed_legs().len(), 1);
+        assert_eq!(set.completed_legs()[0].winner(), Some(0));
+        assert_eq!(set.current_leg().first_player(), 1);
+        assert_eq!(set.current_leg().current_points(), 41);
+    }
+
+    #[test]
+    fn starting_player_win_rate_counts_held_legs() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(7).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        assert_eq!(set.starting_player_win_rate(), 0.0);
+
+        // Anna holds her throw, breaks the throw of Pete and holds again
+        let legs = ["T20 D20 1", "0 0 0 T20 D20 1", "T20 D20 1"];
+        for throw in legs.iter().flat_map(|leg| leg.split(' ')) {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.legs_won(0), 3);
+        assert!((set.starting_player_win_rate() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn level_best_of_three_goes_to_decider() {
+        let participants = test_participants(2);
+        let ruleset = Ruleset::new()
+            .score(101)
+            .unwrap()
+            .sets(SetOptions::new().num_legs(3).unwrap().build())
+            .build();
+
+        let mut set = Set::new(&ruleset, &participants, 0).unwrap();
+        assert!(!set.is_decider());
+
+        // Both players win the leg they started
+        for _ in 0..2 {
+            for throw in ["T20", "D20", "1"] {
+                set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+            }
+        }
+
+        assert_eq!(set.current_leg_number(), 3);
+        assert!(set.is_decider());
+
+        for throw in ["T20", "D20", "1"] {
+            set = set.add_throw(Throw::from_str(throw).unwrap()).unwrap();
+        }
+
+        assert_eq!(set.set_winner(), Some(0));
+        assert!(!set.is_decider());
+    }
 }
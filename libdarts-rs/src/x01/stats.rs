@@ -0,0 +1,84 @@
+use super::leg::Leg;
+
+/// Statistics of a player over several legs, see [aggregate]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionStats {
+    /// Points per three darts over all legs
+    pub three_dart_average: f64,
+    /// Number of 180s
+    pub maximums: usize,
+    /// The highest score the player checked out with
+    pub best_checkout: Option<u32>,
+    pub legs_played: usize,
+}
+
+/// Combine the statistics of a player over the given legs
+///
+/// The average is weighted by the darts thrown in each leg.
+pub fn aggregate(legs: &[Leg], player_index: usize) -> SessionStats {
+    let points: u32 = legs.iter().map(|leg| leg.points_scored(player_index)).sum();
+    let darts: usize = legs.iter().map(|leg| leg.darts_thrown(player_index)).sum();
+
+    SessionStats {
+        three_dart_average: if darts == 0 {
+            0.0
+        } else {
+            f64::from(points) / darts as f64 * 3.0
+        },
+        maximums: legs.iter().map(|leg| leg.maximums(player_index)).sum(),
+        best_checkout: legs
+            .iter()
+            .filter_map(|leg| leg.checkout(player_index))
+            .max(),
+        legs_played: legs.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        throw::Throw,
+        x01::{participants::test_participants, ruleset::Ruleset},
+    };
+
+    use super::*;
+
+    fn throws(notation: &[&str]) -> Vec<Throw> {
+        notation
+            .iter()
+            .map(|throw| Throw::from_str(throw).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn stats_are_aggregated_over_legs() {
+        let participants = test_participants(1);
+        let ruleset = Ruleset::new().score(301).unwrap().build();
+
+        // 180 and a 121 checkout in six darts
+        let first = throws(&["T20", "T20", "T20", "T20", "T17", "D5"]);
+        // Eight darts for 301, finished with 80
+        let second = throws(&["T20", "T20", "1", "20", "20", "T20", "T20", "D10"]);
+
+        let legs = [
+            Leg::from_throws(&ruleset, &participants, 0, &first).unwrap(),
+            Leg::from_throws(&ruleset, &participants, 0, &second).unwrap(),
+        ];
+
+        assert_eq!(legs[0].checkout(0), Some(121));
+        assert_eq!(legs[1].checkout(0), Some(80));
+
+        let stats = aggregate(&legs, 0);
+
+        assert_eq!(stats.legs_played, 2);
+        assert_eq!(stats.maximums, 1);
+        assert_eq!(stats.best_checkout, Some(121));
+        assert!((stats.three_dart_average - 602.0 / 14.0 * 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_legs_result_in_empty_stats() {
+        assert_eq!(aggregate(&[], 0), SessionStats::default());
+    }
+}